@@ -1,3 +1,6 @@
 CREATE SCHEMA application;
 CREATE USER srs_backend WITH PASSWORD 'srs-dev';
 GRANT ALL ON SCHEMA application TO srs_backend;
+-- Used by search, created here as they require privileges which backend does not have.
+CREATE EXTENSION IF NOT EXISTS unaccent SCHEMA public;
+CREATE EXTENSION IF NOT EXISTS pg_trgm SCHEMA public;
@@ -0,0 +1,17 @@
+CREATE TABLE application.league (
+    league_id SERIAL PRIMARY KEY,
+    league_name VARCHAR(100) NOT NULL UNIQUE
+);
+
+INSERT INTO application.league (league_name)
+VALUES ('pge-ekstraliga');
+
+-- Games loaded before leagues were introduced come from PGE Ekstraliga.
+ALTER TABLE application.game
+ADD COLUMN league INTEGER REFERENCES application.league;
+
+UPDATE application.game
+SET league = (SELECT league_id FROM application.league WHERE league_name = 'pge-ekstraliga');
+
+ALTER TABLE application.game
+ALTER COLUMN league SET NOT NULL;
@@ -0,0 +1,6 @@
+ALTER TABLE application.game
+ADD COLUMN round_number SMALLINT,
+ADD COLUMN stage VARCHAR(20) CHECK (stage IN ('regular_season', 'play_off', 'final')),
+ADD COLUMN referee VARCHAR(100),
+ADD COLUMN track_commissioner VARCHAR(100),
+ADD COLUMN attendance INTEGER CHECK (attendance >= 0);
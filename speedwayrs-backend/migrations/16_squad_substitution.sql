@@ -0,0 +1,8 @@
+ALTER TABLE application.squad
+ADD COLUMN substitution VARCHAR(30),
+ADD COLUMN replaced_player INTEGER REFERENCES application.player;
+
+ALTER TABLE application.squad
+ADD CONSTRAINT substitution_enum CHECK (
+  substitution IN ('tactical_reserve', 'tactical_substitution', 'rider_replacement', 'joker')
+);
@@ -0,0 +1,111 @@
+-- Riders are identified by their profile site. Riders loaded without it are still
+-- identified by name, so there can be only one such rider with given name.
+ALTER TABLE application.player
+DROP CONSTRAINT player_name;
+
+ALTER TABLE application.player
+ADD COLUMN source_url TEXT UNIQUE;
+
+CREATE UNIQUE INDEX player_name_unlinked ON application.player (name, sname)
+WHERE source_url IS NULL;
+
+-- Names and profile sites of riders merged into another rider.
+CREATE TABLE application.player_alias (
+  alias_id SERIAL PRIMARY KEY,
+  player_id INTEGER REFERENCES application.player(player_id) NOT NULL,
+  name VARCHAR(50) NOT NULL,
+  sname VARCHAR(50) NOT NULL,
+  source_url TEXT UNIQUE
+);
+
+CREATE INDEX player_alias_name ON application.player_alias (name, sname);
+
+-- Moves every record of `removed` rider to `kept` rider and keeps his name and site as alias.
+CREATE FUNCTION application.merge_players(kept INTEGER, removed INTEGER) RETURNS INTEGER AS $$
+BEGIN
+  IF kept = removed THEN
+    RAISE EXCEPTION 'Player % cannot be merged with itself.', kept;
+  END IF;
+
+  IF NOT EXISTS (SELECT 1 FROM application.player WHERE player_id = kept) THEN
+    RAISE EXCEPTION 'Player % does not exist.', kept;
+  END IF;
+
+  INSERT INTO application.player_alias (player_id, name, sname, source_url)
+  SELECT kept, name, sname, source_url
+  FROM application.player
+  WHERE player_id = removed;
+
+  IF NOT FOUND THEN
+    RAISE EXCEPTION 'Player % does not exist.', removed;
+  END IF;
+
+  UPDATE application.player_alias SET player_id = kept WHERE player_id = removed;
+
+  UPDATE application.squad SET player_id = kept WHERE player_id = removed;
+  UPDATE application.squad SET replaced_player = kept WHERE replaced_player = removed;
+
+  DELETE FROM application.player_score AS score
+  WHERE score.player_id = removed AND EXISTS (
+    SELECT 1 FROM application.player_score AS other
+    WHERE other.player_id = kept AND other.game_id = score.game_id AND other.round = score.round
+  );
+  UPDATE application.player_score SET player_id = kept WHERE player_id = removed;
+
+  DELETE FROM application.game_team AS entry
+  WHERE entry.player = removed AND EXISTS (
+    SELECT 1 FROM application.game_team AS other
+    WHERE other.player = kept AND other.team = entry.team AND other.game = entry.game
+  );
+  UPDATE application.game_team SET player = kept WHERE player = removed;
+
+  DELETE FROM application.player_like AS entry
+  WHERE entry.player_id = removed AND EXISTS (
+    SELECT 1 FROM application.player_like AS other
+    WHERE other.player_id = kept AND other.username = entry.username
+  );
+  UPDATE application.player_like SET player_id = kept WHERE player_id = removed;
+
+  DELETE FROM application.player WHERE player_id = removed;
+
+  RETURN kept;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Moves records of `original` rider from given games to a new rider with given profile site.
+CREATE FUNCTION application.split_player(original INTEGER, games INTEGER[], new_source_url TEXT)
+RETURNS INTEGER AS $$
+DECLARE
+  created INTEGER;
+BEGIN
+  INSERT INTO application.player (name, sname, source_url)
+  SELECT name, sname, new_source_url
+  FROM application.player
+  WHERE player_id = original
+  RETURNING player_id INTO created;
+
+  IF created IS NULL THEN
+    RAISE EXCEPTION 'Player % does not exist.', original;
+  END IF;
+
+  UPDATE application.squad
+  SET player_id = created
+  FROM application.run
+  WHERE run.id = squad.run AND run.game_id = ANY(games) AND squad.player_id = original;
+
+  UPDATE application.squad
+  SET replaced_player = created
+  FROM application.run
+  WHERE run.id = squad.run AND run.game_id = ANY(games) AND squad.replaced_player = original;
+
+  UPDATE application.player_score
+  SET player_id = created
+  WHERE player_id = original AND game_id = ANY(games);
+
+  UPDATE application.game_team
+  SET player = created
+  WHERE player = original AND game = ANY(games);
+
+  RETURN created;
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1,37 @@
+-- Games are identified by teams, date and league, so loading the same file again
+-- updates games instead of inserting them twice.
+ALTER TABLE application.game
+ADD COLUMN source_url TEXT UNIQUE;
+
+-- Only the first copy of every game loaded more than once is kept.
+CREATE TEMPORARY TABLE duplicated_game AS
+SELECT game_id
+FROM (
+  SELECT
+    game_id,
+    ROW_NUMBER() OVER (PARTITION BY team_1, team_2, game_date, league ORDER BY game_id) AS copy
+  FROM application.game
+) AS games
+WHERE copy > 1;
+
+DELETE FROM application.squad
+WHERE run IN (
+  SELECT id FROM application.run WHERE game_id IN (SELECT game_id FROM duplicated_game)
+);
+
+DELETE FROM application.run
+WHERE game_id IN (SELECT game_id FROM duplicated_game);
+
+DELETE FROM application.player_score
+WHERE game_id IN (SELECT game_id FROM duplicated_game);
+
+DELETE FROM application.game_team
+WHERE game IN (SELECT game_id FROM duplicated_game);
+
+DELETE FROM application.game
+WHERE game_id IN (SELECT game_id FROM duplicated_game);
+
+DROP TABLE duplicated_game;
+
+ALTER TABLE application.game
+ADD CONSTRAINT game_natural_key UNIQUE (team_1, team_2, game_date, league);
@@ -0,0 +1,4 @@
+-- Runs, squads and lineups are read and replaced by game.
+CREATE INDEX run_game ON application.run (game_id);
+CREATE INDEX squad_run ON application.squad (run);
+CREATE INDEX game_team_game ON application.game_team (game);
@@ -0,0 +1,3 @@
+-- Hash of loaded game record. Game loaded again without changes is skipped.
+ALTER TABLE application.game
+ADD COLUMN content_hash TEXT;
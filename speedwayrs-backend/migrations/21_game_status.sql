@@ -0,0 +1,17 @@
+-- Games listed in schedule are stored before they are played. Only finished games
+-- are required to have a score, while stadium of a fixture may be unknown.
+ALTER TABLE application.game
+ADD COLUMN status VARCHAR(20) NOT NULL DEFAULT 'finished',
+ALTER COLUMN score_1 DROP NOT NULL,
+ALTER COLUMN score_2 DROP NOT NULL,
+ALTER COLUMN place DROP NOT NULL;
+
+ALTER TABLE application.game
+ADD CONSTRAINT game_status CHECK (
+  status IN ('scheduled', 'live', 'postponed', 'cancelled', 'finished')
+),
+ADD CONSTRAINT game_finished_score CHECK (
+  status <> 'finished' OR (score_1 IS NOT NULL AND score_2 IS NOT NULL)
+);
+
+CREATE INDEX game_status_date ON application.game (status, game_date);
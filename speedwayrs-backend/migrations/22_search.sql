@@ -0,0 +1,19 @@
+-- Extensions are created by db_init.sql, as the backend user is not allowed to create them.
+-- Databases created before need them to be created by superuser before this migration.
+CREATE EXTENSION IF NOT EXISTS unaccent SCHEMA public;
+CREATE EXTENSION IF NOT EXISTS pg_trgm SCHEMA public;
+
+-- Case and accent insensitive form of searched names, e.g. `wlokniarz` for `Włókniarz`.
+-- unaccent() depends on search path, so dictionary is given explicitly to mark the
+-- function as immutable, which allows indexing it.
+CREATE FUNCTION application.search_key(TEXT) RETURNS TEXT AS $$
+  SELECT lower(public.unaccent('public.unaccent'::regdictionary, $1))
+$$ LANGUAGE SQL IMMUTABLE STRICT PARALLEL SAFE;
+
+-- Trigram indexes serve both `LIKE '%text%'` and word similarity (`%>`) of search.
+CREATE INDEX player_search_key ON application.player
+  USING GIN (application.search_key(name || ' ' || sname) public.gin_trgm_ops);
+CREATE INDEX team_search_key ON application.team
+  USING GIN (application.search_key(team_name) public.gin_trgm_ops);
+CREATE INDEX stadium_search_key ON application.stadium
+  USING GIN (application.search_key(location_desc) public.gin_trgm_ops);
@@ -0,0 +1,3 @@
+-- Serialized incidents keep the scraped symbol and do not fit in 30 characters.
+ALTER TABLE application.squad ALTER COLUMN result TYPE TEXT;
+ALTER TABLE application.player_score ALTER COLUMN score TYPE TEXT;
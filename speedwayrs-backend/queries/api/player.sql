@@ -0,0 +1,8 @@
+SELECT
+  player_id,
+  name,
+  sname
+FROM
+  application.player
+WHERE
+  player_id = $1;
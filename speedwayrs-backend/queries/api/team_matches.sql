@@ -0,0 +1,40 @@
+SELECT
+  game.game_id,
+  game.team_1 = $1 AS "home!",
+  opponent.team_id AS opponent_id,
+  opponent.team_name AS opponent_name,
+  (
+    CASE
+      WHEN game.team_1 = $1 THEN game.score_1
+      ELSE game.score_2
+    END
+  ) AS score,
+  (
+    CASE
+      WHEN game.team_1 = $1 THEN game.score_2
+      ELSE game.score_1
+    END
+  ) AS opponent_score,
+  game.game_date,
+  league.league_name,
+  game.status
+FROM
+  application.game
+  JOIN application.team opponent ON opponent.team_id = (
+    CASE
+      WHEN game.team_1 = $1 THEN game.team_2
+      ELSE game.team_1
+    END
+  )
+  JOIN application.league ON league.league_id = game.league
+WHERE
+  (game.team_1 = $1 OR game.team_2 = $1)
+  AND ($2::INTEGER IS NULL OR EXTRACT(YEAR FROM game.game_date) = $2)
+  AND ($3::TEXT IS NULL OR league.league_name = $3)
+  AND ($4::TEXT IS NULL OR game.status = $4)
+  AND ($5::INTEGER IS NULL OR opponent.team_id = $5)
+ORDER BY
+  game.game_date DESC,
+  game.game_id DESC
+LIMIT $6
+OFFSET $7;
@@ -0,0 +1,16 @@
+SELECT
+  COUNT(*) AS "total!"
+FROM
+  application.game
+  JOIN application.league ON league.league_id = game.league
+WHERE
+  (game.team_1 = $1 OR game.team_2 = $1)
+  AND ($2::INTEGER IS NULL OR EXTRACT(YEAR FROM game.game_date) = $2)
+  AND ($3::TEXT IS NULL OR league.league_name = $3)
+  AND ($4::TEXT IS NULL OR game.status = $4)
+  AND ($5::INTEGER IS NULL OR (
+    CASE
+      WHEN game.team_1 = $1 THEN game.team_2
+      ELSE game.team_1
+    END
+  ) = $5);
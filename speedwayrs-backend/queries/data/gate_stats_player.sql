@@ -0,0 +1,8 @@
+SELECT
+  squad.color AS "color!",
+  squad.result
+FROM
+  application.squad
+WHERE
+  squad.player_id = $1
+  AND squad.color IS NOT NULL;
@@ -0,0 +1,10 @@
+SELECT
+  squad.color AS "color!",
+  squad.result
+FROM
+  application.squad
+  JOIN application.run ON run.id = squad.run
+  JOIN application.game ON game.game_id = run.game_id
+WHERE
+  game.place = $1
+  AND squad.color IS NOT NULL;
@@ -0,0 +1,23 @@
+SELECT
+  game.game_id,
+  t1.team_name AS team1,
+  game.team_1 AS team1_id,
+  t2.team_name AS team2,
+  game.team_2 AS team2_id,
+  game.game_date AS date,
+  game.status,
+  league.league_name AS league
+FROM
+  application.game
+  JOIN application.team t1 ON t1.team_id = game.team_1
+  JOIN application.team t2 ON t2.team_id = game.team_2
+  JOIN application.league ON league.league_id = game.league
+WHERE
+  game.status <> 'finished'
+  -- Postponed games keep their previous date until a new one is announced.
+  AND (game.game_date >= CURRENT_DATE OR game.status IN ('live', 'postponed'))
+  AND ($1::INTEGER IS NULL OR game.team_1 = $1 OR game.team_2 = $1)
+  AND ($2::INTEGER IS NULL OR game.league = $2)
+ORDER BY
+  date ASC
+LIMIT $3;
@@ -9,6 +9,9 @@ SELECT
 FROM
   application.game JOIN application.team t1 ON t1.team_id = game.team_1
   JOIN application.team t2 ON t2.team_id = game.team_2
+WHERE
+  ($3::INTEGER IS NULL OR game.league = $3)
+  AND game.status IN ('live', 'finished')
 ORDER BY
   date DESC
 LIMIT $1
@@ -0,0 +1,7 @@
+SELECT
+  league_id,
+  league_name
+FROM
+  application.league
+ORDER BY
+  league_name;
@@ -17,6 +17,8 @@ SELECT
 FROM
   liked_teams JOIN application.game ON (game.team_1 = liked_teams.team OR game.team_2 = liked_teams.team) JOIN application.team t1 ON t1.team_id = game.team_1
   JOIN application.team t2 ON t2.team_id = game.team_2
+WHERE
+  game.status IN ('live', 'finished')
 ORDER BY
   date DESC
 LIMIT $2;
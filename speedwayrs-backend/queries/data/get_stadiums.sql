@@ -0,0 +1,7 @@
+SELECT
+  stadium_id,
+  location_desc
+FROM
+  application.stadium
+ORDER BY
+  location_desc;
@@ -3,9 +3,15 @@ SELECT
   squad.result,
   player.name,
   player.sname,
-  squad.player_id
+  squad.player_id,
+  squad.color,
+  squad.substitution,
+  squad.replaced_player,
+  replaced.name AS "replaced_name?",
+  replaced.sname AS "replaced_sname?"
 FROM
   application.run LEFT JOIN application.squad ON squad.run = run.id
   LEFT JOIN application.player ON player.player_id = squad.player_id 
+  LEFT JOIN application.player AS replaced ON replaced.player_id = squad.replaced_player
 WHERE
   run.game_id = $1;
@@ -1,7 +1,22 @@
 SELECT
-  *
+  game.game_id,
+  game.team_1,
+  game.team_2,
+  game.score_1,
+  game.score_2,
+  game.game_date,
+  game.status,
+  game.round_number,
+  game.stage,
+  game.referee,
+  game.track_commissioner,
+  game.attendance,
+  -- Stadium of a fixture may be unknown.
+  stadium.location_desc AS "location_desc?",
+  league.league_name
 FROM
   application.game
   LEFT JOIN application.stadium ON stadium.stadium_id = game.place
+  JOIN application.league ON league.league_id = game.league
 WHERE
   game_id = $1;
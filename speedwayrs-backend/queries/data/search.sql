@@ -0,0 +1,126 @@
+WITH query AS (
+  SELECT
+    application.search_key($1) AS key
+),
+-- Games are matched by keys of their teams, which are computed once for every team.
+team_key AS (
+  SELECT
+    team.team_id,
+    team.team_name,
+    application.search_key(team.team_name) AS key
+  FROM
+    application.team
+),
+-- Names are found by searched text ($5 is its escaped LIKE pattern) or by similar words,
+-- both served by trigram indexes.
+candidates AS (
+  SELECT
+    'player' AS kind,
+    player.player_id AS id,
+    player.name AS name,
+    player.sname AS sname,
+    NULL::TEXT AS team2,
+    NULL::TIMESTAMPTZ AS date,
+    NULL::TEXT AS league,
+    NULL::TEXT AS status,
+    application.search_key(player.name || ' ' || player.sname) AS key
+  FROM
+    application.player
+  WHERE
+    ($2::TEXT[] IS NULL OR 'player' = ANY($2))
+    AND (
+      application.search_key(player.name || ' ' || player.sname) LIKE application.search_key($5)
+      OR application.search_key(player.name || ' ' || player.sname) %> application.search_key($1)
+    )
+  UNION ALL
+  SELECT
+    'team',
+    team.team_id,
+    team.team_name,
+    NULL,
+    NULL,
+    NULL,
+    NULL,
+    NULL,
+    application.search_key(team.team_name)
+  FROM
+    application.team
+  WHERE
+    ($2::TEXT[] IS NULL OR 'team' = ANY($2))
+    AND (
+      application.search_key(team.team_name) LIKE application.search_key($5)
+      OR application.search_key(team.team_name) %> application.search_key($1)
+    )
+  UNION ALL
+  SELECT
+    'stadium',
+    stadium.stadium_id,
+    stadium.location_desc,
+    NULL,
+    NULL,
+    NULL,
+    NULL,
+    NULL,
+    application.search_key(stadium.location_desc)
+  FROM
+    application.stadium
+  WHERE
+    ($2::TEXT[] IS NULL OR 'stadium' = ANY($2))
+    AND (
+      application.search_key(stadium.location_desc) LIKE application.search_key($5)
+      OR application.search_key(stadium.location_desc) %> application.search_key($1)
+    )
+  UNION ALL
+  -- Games are found by names of both teams, e.g. `unia sparta`.
+  SELECT
+    'match',
+    game.game_id,
+    t1.team_name,
+    NULL,
+    t2.team_name,
+    game.game_date,
+    league.league_name,
+    game.status,
+    t1.key || ' ' || t2.key
+  FROM
+    application.game
+    JOIN team_key t1 ON t1.team_id = game.team_1
+    JOIN team_key t2 ON t2.team_id = game.team_2
+    JOIN application.league ON league.league_id = game.league
+  WHERE
+    ($2::TEXT[] IS NULL OR 'match' = ANY($2))
+    AND (
+      t1.key || ' ' || t2.key LIKE application.search_key($5)
+      OR t1.key || ' ' || t2.key %> application.search_key($1)
+    )
+)
+-- Single row with total and empty page is returned for pages after the last one.
+SELECT
+  totals.total AS "total!",
+  page.kind AS "kind?",
+  page.id AS "id?",
+  page.name AS "name?",
+  page.sname,
+  page.team2,
+  page.date,
+  page.league,
+  page.status
+FROM
+  (SELECT COUNT(*) AS total FROM candidates) totals
+  LEFT JOIN LATERAL (
+    SELECT
+      candidates.*
+    FROM
+      candidates,
+      query
+    ORDER BY
+      -- Names starting with searched text go first, then the most similar ones.
+      starts_with(candidates.key, query.key) DESC,
+      word_similarity(query.key, candidates.key) DESC,
+      similarity(query.key, candidates.key) DESC,
+      candidates.date DESC NULLS LAST,
+      candidates.name ASC,
+      candidates.id ASC
+    LIMIT $3
+    OFFSET $4
+  ) page ON TRUE;
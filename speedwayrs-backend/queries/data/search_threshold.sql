@@ -0,0 +1,2 @@
+-- Applies only to the current transaction.
+SELECT set_config('pg_trgm.word_similarity_threshold', $1, true);
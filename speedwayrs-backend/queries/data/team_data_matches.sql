@@ -20,8 +20,8 @@ JOIN application.team n1
 JOIN application.team n2
   ON n2.team_id = team_2
 WHERE
-  team_1 = $1
-  OR team_2 = $1
+  (team_1 = $1 OR team_2 = $1)
+  AND status IN ('live', 'finished')
 ORDER BY
   game_date DESC
 OFFSET $2
@@ -5,4 +5,4 @@ SELECT
 FROM
   application.player
 WHERE
-  lower(concat(name, sname)) LIKE $1;
+  application.search_key(name || ' ' || sname) LIKE application.search_key($1);
@@ -4,4 +4,4 @@ SELECT
 FROM
     application.team
 WHERE
-    lower(team_name) LIKE $1;
+    application.search_key(team_name) LIKE application.search_key($1);
@@ -7,6 +7,7 @@ WITH team_left_stats AS (
     application.game
   WHERE
     team_1 = $1
+    AND status = 'finished'
 ), team_right_stats AS (
   SELECT
     COALESCE(SUM(CASE WHEN score_1 < score_2 THEN 1 ELSE 0 END), 0) AS wins,
@@ -15,7 +16,8 @@ WITH team_left_stats AS (
   FROM
     application.game
   WHERE
-    team_2 = $1 
+    team_2 = $1
+    AND status = 'finished'
 )
 SELECT
   COALESCE(left_t.wins + right_t.wins, 0) AS wins,
@@ -8,6 +8,7 @@ WITH left_normalized AS (
     application.game
   WHERE
     game.team_2 = $1
+    AND game.status = 'finished'
 ), results AS (
   SELECT
     game.game_id,
@@ -18,6 +19,7 @@ WITH left_normalized AS (
     application.game
   WHERE
     game.team_1 = $1
+    AND game.status = 'finished'
   UNION ALL
   SELECT
     *
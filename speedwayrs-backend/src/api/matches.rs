@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use sqlx::PgPool;
+
+use super::cached;
+use crate::data::match_info::select_match;
+
+/// Returns result of game with all its runs. Games which were not played yet have no runs.
+#[utoipa::path(
+    get,
+    path = "/api/v1/matches/{id}",
+    tag = "matches",
+    params(("id" = i32, Path, description = "Identifier of game.")),
+    responses(
+        (status = 200, description = "Result of game.", body = MatchResult),
+        (status = 404, description = "Game does not exist.")
+    )
+)]
+pub(super) async fn match_details(
+    State(db): State<Arc<PgPool>>,
+    Path(id): Path<i32>,
+) -> impl IntoResponse {
+    match select_match(id, &db).await {
+        Ok(mut result) => {
+            result.sort_runs();
+
+            cached(result)
+        }
+        Err(status) => status.into_response(),
+    }
+}
@@ -0,0 +1,80 @@
+mod matches;
+mod players;
+mod teams;
+
+use axum::{
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use http::{header, StatusCode};
+use serde::Serialize;
+use speedwayrs_types::{
+    Helmet, IncidentKind, MatchDetails, MatchResult, MatchStatus, Player, PlayerResult, RunInfo,
+    RunPlayerScore, Stage, Substitution, SubstitutionKind,
+};
+use utoipa::OpenApi;
+
+use crate::{pagination::Pagination, AppData};
+
+// Responses do not depend on session of user, so they may be stored by clients and proxies.
+const CACHE_CONTROL: &str = "public, max-age=60";
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        players::player,
+        teams::team,
+        teams::team_matches,
+        matches::match_details
+    ),
+    components(schemas(
+        players::PlayerDetails,
+        players::Discipline,
+        players::FormerTeam,
+        teams::Team,
+        teams::TeamMatch,
+        teams::TeamMatches,
+        Pagination,
+        MatchResult,
+        MatchStatus,
+        MatchDetails,
+        Stage,
+        RunInfo,
+        RunPlayerScore,
+        Helmet,
+        Substitution,
+        SubstitutionKind,
+        Player,
+        PlayerResult,
+        IncidentKind
+    )),
+    tags(
+        (name = "players", description = "Riders and their statistics."),
+        (name = "teams", description = "Teams and their games."),
+        (name = "matches", description = "Results of games.")
+    )
+)]
+struct ApiDoc;
+
+fn cached<T: Serialize>(body: T) -> Response {
+    (
+        StatusCode::OK,
+        [(header::CACHE_CONTROL, CACHE_CONTROL)],
+        Json(body),
+    )
+        .into_response()
+}
+
+async fn openapi() -> Json<utoipa::openapi::OpenApi> {
+    Json(ApiDoc::openapi())
+}
+
+pub fn api_router() -> Router<AppData> {
+    Router::new()
+        .route("/openapi.json", get(openapi))
+        .route("/players/:id", get(players::player))
+        .route("/teams/:id", get(teams::team))
+        .route("/teams/:id/matches", get(teams::team_matches))
+        .route("/matches/:id", get(matches::match_details))
+}
@@ -0,0 +1,113 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+};
+use http::StatusCode;
+use serde::Serialize;
+use speedwayrs_types::IncidentKind;
+use sqlx::PgPool;
+use utoipa::ToSchema;
+
+use super::cached;
+use crate::data::player_data::{get_former_teams, get_player_stats};
+
+/// Number of rides without points for single reason.
+#[derive(Serialize, ToSchema)]
+pub(super) struct Discipline {
+    kind: IncidentKind,
+    rides: u16,
+}
+
+#[derive(Serialize, ToSchema)]
+pub(super) struct FormerTeam {
+    id: i32,
+    name: String,
+    /// Number of games ridden for the team.
+    games: u16,
+}
+
+/// Statistics of rider from all scraped games.
+#[derive(Serialize, ToSchema)]
+pub(super) struct PlayerDetails {
+    id: i32,
+    name: String,
+    sname: String,
+    three_points: u32,
+    two_points: u32,
+    one_points: u32,
+    zero_points: u32,
+    stars: u32,
+    /// Number of rides without points.
+    accidents: u16,
+    discipline: Vec<Discipline>,
+    teams: Vec<FormerTeam>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/players/{id}",
+    tag = "players",
+    params(("id" = i32, Path, description = "Identifier of player.")),
+    responses(
+        (status = 200, description = "Statistics of player.", body = PlayerDetails),
+        (status = 404, description = "Player does not exist.")
+    )
+)]
+pub(super) async fn player(
+    State(db): State<Arc<PgPool>>,
+    Path(id): Path<i32>,
+) -> impl IntoResponse {
+    let player = match sqlx::query_file!("queries/api/player.sql", id)
+        .fetch_optional(db.as_ref())
+        .await
+    {
+        Ok(None) => return (StatusCode::NOT_FOUND).into_response(),
+        Ok(Some(record)) => record,
+        Err(e) => {
+            tracing::error!("Error returned while querying player. Error = [{e:?}]");
+
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let stats = match get_player_stats(db.as_ref(), id).await {
+        Ok(stats) => stats,
+        Err(e) => {
+            tracing::error!("Error returned while getting player stats. Error = [{e:?}]");
+
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let teams = match get_former_teams(db.as_ref(), id).await {
+        Ok(teams) => teams,
+        Err(e) => {
+            tracing::error!("Error returned while getting former teams. Error = [{e:?}]");
+
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    cached(PlayerDetails {
+        id: player.player_id,
+        name: player.name,
+        sname: player.sname,
+        three_points: stats.three_points,
+        two_points: stats.two_points,
+        one_points: stats.one_points,
+        zero_points: stats.zero_points,
+        stars: stats.stars,
+        accidents: stats.accidents,
+        discipline: stats
+            .discipline
+            .into_iter()
+            .map(|(kind, rides)| Discipline { kind, rides })
+            .collect(),
+        teams: teams
+            .into_iter()
+            .map(|(id, name, games)| FormerTeam { id, name, games })
+            .collect(),
+    })
+}
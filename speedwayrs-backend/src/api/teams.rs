@@ -0,0 +1,189 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query, State},
+    response::IntoResponse,
+};
+use http::StatusCode;
+use serde::{Deserialize, Serialize};
+use speedwayrs_types::MatchStatus;
+use sqlx::PgPool;
+use utoipa::{IntoParams, ToSchema};
+
+use super::cached;
+use crate::pagination::{PageRequest, Pagination};
+
+#[derive(Serialize, ToSchema)]
+pub(super) struct Team {
+    id: i32,
+    name: String,
+}
+
+/// Game seen from the side of requested team.
+#[derive(Serialize, ToSchema)]
+pub(super) struct TeamMatch {
+    id: i32,
+    /// True if requested team was the host.
+    home: bool,
+    opponent: Team,
+    /// Scores are missing for games which were not played.
+    score: Option<i32>,
+    opponent_score: Option<i32>,
+    #[serde(with = "time::serde::rfc3339")]
+    date: time::OffsetDateTime,
+    league: String,
+    status: MatchStatus,
+}
+
+#[derive(Serialize, ToSchema)]
+pub(super) struct TeamMatches {
+    pagination: Pagination,
+    matches: Vec<TeamMatch>,
+}
+
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub(super) struct TeamMatchesQuery {
+    /// Year in which games were played.
+    season: Option<i32>,
+    league: Option<String>,
+    status: Option<MatchStatus>,
+    /// Identifier of opposing team.
+    opponent: Option<i32>,
+    /// Number of page, counted from 1.
+    page: Option<i64>,
+    /// Number of games on page, from 1 to 100. Defaults to 20.
+    per_page: Option<i64>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/teams/{id}",
+    tag = "teams",
+    params(("id" = i32, Path, description = "Identifier of team.")),
+    responses(
+        (status = 200, description = "Team.", body = Team),
+        (status = 404, description = "Team does not exist.")
+    )
+)]
+pub(super) async fn team(
+    State(db): State<Arc<PgPool>>,
+    Path(id): Path<i32>,
+) -> impl IntoResponse {
+    match sqlx::query_file!("queries/data/team_data_name.sql", id)
+        .fetch_optional(db.as_ref())
+        .await
+    {
+        Ok(None) => (StatusCode::NOT_FOUND).into_response(),
+        Ok(Some(record)) => cached(Team {
+            id,
+            name: record.team_name,
+        }),
+        Err(e) => {
+            tracing::error!("Error returned while querying team. Error = [{e:?}]");
+
+            (StatusCode::INTERNAL_SERVER_ERROR).into_response()
+        }
+    }
+}
+
+/// Returns games of team from the latest ones.
+#[utoipa::path(
+    get,
+    path = "/api/v1/teams/{id}/matches",
+    tag = "teams",
+    params(("id" = i32, Path, description = "Identifier of team."), TeamMatchesQuery),
+    responses(
+        (status = 200, description = "Page of games.", body = TeamMatches),
+        (status = 400, description = "Page is out of range."),
+        (status = 404, description = "Team does not exist.")
+    )
+)]
+pub(super) async fn team_matches(
+    State(db): State<Arc<PgPool>>,
+    Path(id): Path<i32>,
+    Query(query): Query<TeamMatchesQuery>,
+) -> impl IntoResponse {
+    let Some(page) = PageRequest::new(query.page, query.per_page) else {
+        return (StatusCode::BAD_REQUEST).into_response();
+    };
+
+    match sqlx::query_file!("queries/data/team_data_name.sql", id)
+        .fetch_optional(db.as_ref())
+        .await
+    {
+        Ok(Some(_)) => {}
+        Ok(None) => return (StatusCode::NOT_FOUND).into_response(),
+        Err(e) => {
+            tracing::error!("Error returned while querying team. Error = [{e:?}]");
+
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    }
+
+    let status = query.status.map(|status| status.as_str());
+
+    let total = sqlx::query_file!(
+        "queries/api/team_matches_count.sql",
+        id,
+        query.season,
+        query.league,
+        status,
+        query.opponent
+    )
+    .fetch_one(db.as_ref())
+    .await;
+
+    let total = match total {
+        Ok(record) => record.total,
+        Err(e) => {
+            tracing::error!("Error returned while counting team games. Error = [{e:?}]");
+
+            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+        }
+    };
+
+    let games = sqlx::query_file!(
+        "queries/api/team_matches.sql",
+        id,
+        query.season,
+        query.league,
+        status,
+        query.opponent,
+        page.limit(),
+        page.offset()
+    )
+    .fetch_all(db.as_ref())
+    .await;
+
+    match games {
+        Ok(games) => {
+            let matches = games
+                .into_iter()
+                .map(|record| TeamMatch {
+                    id: record.game_id,
+                    home: record.home,
+                    opponent: Team {
+                        id: record.opponent_id,
+                        name: record.opponent_name,
+                    },
+                    score: record.score,
+                    opponent_score: record.opponent_score,
+                    date: record.game_date,
+                    league: record.league_name,
+                    status: record.status.parse().unwrap_or_default(),
+                })
+                .collect();
+
+            cached(TeamMatches {
+                pagination: page.pagination(total),
+                matches,
+            })
+        }
+        Err(e) => {
+            tracing::error!("Error returned while querying team games. Error = [{e:?}]");
+
+            (StatusCode::INTERNAL_SERVER_ERROR).into_response()
+        }
+    }
+}
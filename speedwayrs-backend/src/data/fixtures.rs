@@ -0,0 +1,51 @@
+use std::sync::Arc;
+
+use axum::{extract::State, response::IntoResponse, Json};
+use http::StatusCode;
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgPool};
+
+#[derive(Deserialize)]
+pub struct FixturesRequest {
+    team: Option<i32>,
+    league: Option<i32>,
+}
+
+#[derive(Serialize, FromRow)]
+struct Fixture {
+    game_id: i32,
+    team1: String,
+    team1_id: i32,
+    team2: String,
+    team2_id: i32,
+    date: time::OffsetDateTime,
+    status: String,
+    league: String,
+}
+
+const FIXTURES_LIMIT: i64 = 50;
+
+/// Returns upcoming games, optionally only of given team or league.
+pub async fn fixtures(
+    State(db): State<Arc<PgPool>>,
+    Json(info): Json<FixturesRequest>,
+) -> impl IntoResponse {
+    let query = sqlx::query_file_as!(
+        Fixture,
+        "queries/data/get_fixtures.sql",
+        info.team,
+        info.league,
+        FIXTURES_LIMIT
+    )
+    .fetch_all(db.as_ref())
+    .await;
+
+    match query {
+        Err(e) => {
+            tracing::error!("Error returned while querying fixtures. Error = [{e:?}]");
+
+            (StatusCode::INTERNAL_SERVER_ERROR).into_response()
+        }
+        Ok(rows) => (StatusCode::OK, Json(rows)).into_response(),
+    }
+}
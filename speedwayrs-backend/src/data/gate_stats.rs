@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use axum::{extract::State, response::IntoResponse, Json};
+use http::StatusCode;
+use serde::{Deserialize, Serialize};
+use speedwayrs_types::{Helmet, PlayerResult};
+use sqlx::{FromRow, PgPool};
+
+#[derive(Deserialize)]
+pub struct PlayerGates {
+    player: i32,
+}
+
+#[derive(Deserialize)]
+pub struct StadiumGates {
+    stadium_id: i32,
+}
+
+#[derive(Serialize, FromRow)]
+struct Stadium {
+    stadium_id: i32,
+    location_desc: String,
+}
+
+#[derive(Serialize, Default, Clone, Copy)]
+struct GateStats {
+    gate: u8,
+    runs: u32,
+    wins: u32,
+    points: u32,
+    average_points: f32,
+}
+
+/// Summarizes runs started from every gate. Runs without a known helmet colour are skipped.
+fn summarize(records: impl Iterator<Item = (String, String)>) -> Vec<GateStats> {
+    let mut gates: Vec<GateStats> = (1..=4)
+        .map(|gate| GateStats {
+            gate,
+            ..Default::default()
+        })
+        .collect();
+
+    for (color, result) in records {
+        let Ok(helmet) = color.parse::<Helmet>() else {
+            tracing::error!("Error while parsing Helmet. Value = [{color}]");
+
+            continue;
+        };
+
+        let entry = &mut gates[helmet.gate() as usize - 1];
+        entry.runs += 1;
+
+        match PlayerResult::from_str(&result) {
+            Some(PlayerResult::Score(score)) | Some(PlayerResult::ScoreWithStar(score)) => {
+                entry.points += score as u32;
+
+                if score == 3 {
+                    entry.wins += 1;
+                }
+            }
+            Some(_) => {}
+            None => {
+                tracing::error!("Error while parsing PlayerResult. Value = [{result}]");
+            }
+        }
+    }
+
+    for entry in gates.iter_mut().filter(|entry| entry.runs > 0) {
+        entry.average_points = entry.points as f32 / entry.runs as f32;
+    }
+
+    gates
+}
+
+pub async fn player_gate_stats(
+    State(db): State<Arc<PgPool>>,
+    Json(info): Json<PlayerGates>,
+) -> impl IntoResponse {
+    let query = sqlx::query_file!("queries/data/gate_stats_player.sql", info.player)
+        .fetch_all(db.as_ref())
+        .await;
+
+    match query {
+        Err(e) => {
+            tracing::error!("Error returned while querying player gate stats. Error = [{e:?}]");
+
+            (StatusCode::INTERNAL_SERVER_ERROR).into_response()
+        }
+        Ok(records) => {
+            let stats = summarize(records.into_iter().map(|record| (record.color, record.result)));
+
+            (StatusCode::OK, Json(stats)).into_response()
+        }
+    }
+}
+
+pub async fn stadium_gate_stats(
+    State(db): State<Arc<PgPool>>,
+    Json(info): Json<StadiumGates>,
+) -> impl IntoResponse {
+    let query = sqlx::query_file!("queries/data/gate_stats_stadium.sql", info.stadium_id)
+        .fetch_all(db.as_ref())
+        .await;
+
+    match query {
+        Err(e) => {
+            tracing::error!("Error returned while querying stadium gate stats. Error = [{e:?}]");
+
+            (StatusCode::INTERNAL_SERVER_ERROR).into_response()
+        }
+        Ok(records) => {
+            let stats = summarize(records.into_iter().map(|record| (record.color, record.result)));
+
+            (StatusCode::OK, Json(stats)).into_response()
+        }
+    }
+}
+
+pub async fn stadiums(State(db): State<Arc<PgPool>>) -> impl IntoResponse {
+    let query = sqlx::query_file_as!(Stadium, "queries/data/get_stadiums.sql")
+        .fetch_all(db.as_ref())
+        .await;
+
+    match query {
+        Err(e) => {
+            tracing::error!("Error returned while querying stadiums. Error = [{e:?}]");
+
+            (StatusCode::INTERNAL_SERVER_ERROR).into_response()
+        }
+        Ok(rows) => (StatusCode::OK, Json(rows)).into_response(),
+    }
+}
@@ -21,13 +21,20 @@ struct ResponseForm {
 
 #[derive(Deserialize)]
 pub struct RequestInfo {
-    page: i64 
+    page: i64,
+    league: Option<i32>
+}
+
+#[derive(Serialize, FromRow)]
+struct League {
+    league_id: i32,
+    league_name: String
 }
 
 const PAGE_SIZE: i64 = 5;
 
 pub async fn last_games(State(db): State<Arc<PgPool>>, Json(info): Json<RequestInfo>) -> impl IntoResponse {
-    let query = sqlx::query_file_as!(ResponseForm, "queries/data/get_last_games.sql", PAGE_SIZE, (info.page - 1) * PAGE_SIZE)
+    let query = sqlx::query_file_as!(ResponseForm, "queries/data/get_last_games.sql", PAGE_SIZE, (info.page - 1) * PAGE_SIZE, info.league)
         .fetch_all(db.as_ref())
         .await;
 
@@ -43,6 +50,23 @@ pub async fn last_games(State(db): State<Arc<PgPool>>, Json(info): Json<RequestI
     }
 }
 
+pub async fn leagues(State(db): State<Arc<PgPool>>) -> impl IntoResponse {
+    let query = sqlx::query_file_as!(League, "queries/data/get_leagues.sql")
+        .fetch_all(db.as_ref())
+        .await;
+
+    match query {
+        Err(e) => {
+            tracing::error!("Error returned while querying leagues. Error = [{e:?}]");
+
+            (StatusCode::INTERNAL_SERVER_ERROR).into_response()
+        }
+        Ok(rows) => {
+            (StatusCode::OK, Json(rows)).into_response()
+        }
+    }
+}
+
 pub async fn liked_teams(State(db): State<Arc<PgPool>>, jar: CookieJar) -> impl IntoResponse {
     let session_id = Uuid::parse_str(jar.get(SESSION_COOKIE).expect("Cannot find session cookie.").value()).expect("Middleware does not work as expected.");
 
@@ -5,7 +5,10 @@ use axum::{
     response::IntoResponse,
 };
 use http::StatusCode;
-use speedwayrs_types::{MatchResult, Player, PlayerResult, RunInfo};
+use speedwayrs_types::{
+    Helmet, MatchDetails, MatchResult, MatchStatus, Player, PlayerResult, RunInfo,
+    RunPlayerScore, Stage, Substitution, SubstitutionKind,
+};
 use sqlx::{FromRow, PgPool};
 
 use serde::Deserialize;
@@ -14,11 +17,14 @@ use serde::Deserialize;
 struct MatchMainInfo {
     game_id: i32,
     team_1: String,
-    score_1: i32,
-    score_2: i32,
+    score_1: Option<i32>,
+    score_2: Option<i32>,
     team_2: String,
-    place: String,
+    place: Option<String>,
     game_date: time::OffsetDateTime,
+    league: String,
+    status: MatchStatus,
+    details: MatchDetails,
 }
 
 #[derive(Deserialize)]
@@ -65,6 +71,15 @@ async fn select_main_data(
             team_2,
             score_2: record.score_2,
             game_date: record.game_date,
+            league: record.league_name,
+            status: record.status.parse().unwrap_or_default(),
+            details: MatchDetails::new(
+                record.round_number.and_then(|round| u8::try_from(round).ok()),
+                record.stage.and_then(|stage| stage.parse::<Stage>().ok()),
+                record.referee,
+                record.track_commissioner,
+                record.attendance.and_then(|attendance| u32::try_from(attendance).ok()),
+            ),
         }))
     } else {
         Ok(None)
@@ -77,7 +92,12 @@ struct RunPlayerInfo {
     result: String,
     name: String,
     sname: String,
-    player_id: i32
+    player_id: i32,
+    color: Option<String>,
+    substitution: Option<String>,
+    replaced_player: Option<i32>,
+    replaced_name: Option<String>,
+    replaced_sname: Option<String>,
 }
 
 async fn select_game_runs_info(
@@ -115,20 +135,15 @@ fn combine_info(
     run_times: Vec<RunTimesInfo>,
     player_scores: Vec<PlayerScoreInfo>,
 ) -> Result<MatchResult, &'static str> {
-    let runs_by_time = run_times
-        .iter()
-        .max_by_key(|record| record.run_position)
-        .ok_or_else(|| "Unable to find maximum runs_by_time.")?;
-    let runs_by_game = game_runs
-        .iter()
-        .max_by_key(|record| record.run_position)
-        .ok_or_else(|| "Unable to find maximum runs_by_game.")?;
-
-    if runs_by_time.run_position != runs_by_game.run_position {
+    // Games which were not played yet have no runs at all.
+    let runs_by_time = run_times.iter().map(|record| record.run_position).max();
+    let runs_by_game = game_runs.iter().map(|record| record.run_position).max();
+
+    if runs_by_time != runs_by_game {
         return Err("Total number of runs does not match.");
     }
 
-    let mut run_infos: HashMap<i32, Vec<(i32, String, String)>> = HashMap::new();
+    let mut run_infos: HashMap<i32, Vec<RunPlayerScore>> = HashMap::new();
 
     for run in game_runs.iter() {
         let entry = match run_infos.get_mut(&run.run_position) {
@@ -141,7 +156,32 @@ fn combine_info(
             }
         };
 
-        entry.push((run.player_id, format!("{} {}", run.name, run.sname), run.result.clone()));
+        let helmet = run
+            .color
+            .as_deref()
+            .and_then(|color| color.parse::<Helmet>().ok());
+
+        let substitution = run
+            .substitution
+            .as_deref()
+            .and_then(|kind| kind.parse::<SubstitutionKind>().ok())
+            .map(|kind| {
+                let replaced = (&run.replaced_name, &run.replaced_sname);
+                let replaced_player = match (run.replaced_player, replaced) {
+                    (Some(id), (Some(name), Some(sname))) => Some((id, format!("{name} {sname}"))),
+                    _ => None,
+                };
+
+                Substitution::new(kind, replaced_player)
+            });
+
+        entry.push(RunPlayerScore::new(
+            run.player_id,
+            format!("{} {}", run.name, run.sname),
+            run.result.clone(),
+            helmet,
+            substitution,
+        ));
     }
 
     let mut runs = Vec::new();
@@ -189,13 +229,17 @@ fn combine_info(
         players.push(Player::new(name, sname, scores));
     }
 
+    // Games which were not played yet have no score.
     Ok(MatchResult::new(
         main_data.team_1,
         main_data.team_2,
-        main_data.score_1 as u32,
-        main_data.score_2 as u32,
-        main_data.place,
+        main_data.score_1.unwrap_or_default() as u32,
+        main_data.score_2.unwrap_or_default() as u32,
+        main_data.place.unwrap_or_default(),
         main_data.game_date,
+        main_data.league,
+        main_data.status,
+        main_data.details,
         runs,
         players,
     ))
@@ -237,66 +281,72 @@ async fn select_player_scores(
     Ok(Some(result_vec))
 }
 
-pub async fn match_info_handler(
-    State(db): State<Arc<PgPool>>,
-    Json(info): Json<HandlerInfo>,
-) -> impl IntoResponse {
-    let match_main_data = match select_main_data(info.match_id, &db).await {
-        Ok(None) => return (StatusCode::NOT_FOUND).into_response(),
+/// Collects everything known about game. Errors are logged and turned into status codes
+/// of response.
+pub(crate) async fn select_match(game_id: i32, db: &Arc<PgPool>) -> Result<MatchResult, StatusCode> {
+    let match_main_data = match select_main_data(game_id, db).await {
+        Ok(None) => return Err(StatusCode::NOT_FOUND),
         Ok(Some(record)) => record,
         Err(e) => {
             tracing::error!(
                 "Error retured from database while querying main match info. Error = [{e:?}]"
             );
 
-            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
         }
     };
 
-    let game_runs = match select_game_runs_info(info.match_id, &db).await {
+    let game_runs = match select_game_runs_info(game_id, db).await {
         Ok(vec) => vec,
         Err(e) => {
             tracing::error!(
                 "Error returned from database while querying game runs info. Error = [{e:?}]"
             );
 
-            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
         }
     };
 
-    let run_times = match select_game_times(info.match_id, &db).await {
+    let run_times = match select_game_times(game_id, db).await {
         Ok(vec) => vec,
         Err(e) => {
             tracing::error!(
                 "Error returned from database while querying runs time. Error = [{e:?}]"
             );
 
-            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
         }
     };
 
-    let player_scores = match select_player_scores(info.match_id, &db).await {
+    let player_scores = match select_player_scores(game_id, db).await {
         Ok(Some(vec)) => vec,
         Ok(None) => {
             tracing::error!("None value returned while querying about player scores.");
 
-            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
         }
         Err(e) => {
             tracing::error!(
                 "Error returned from database while querying about player scores. Error = [{e:?}]"
             );
 
-            return (StatusCode::INTERNAL_SERVER_ERROR).into_response();
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
         }
     };
 
-    match combine_info(match_main_data, game_runs, run_times, player_scores) {
-        Ok(result) => (StatusCode::OK, Json(result)).into_response(),
-        Err(e) => {
-            tracing::error!("Error while combining results. Error = [{e:?}]");
+    combine_info(match_main_data, game_runs, run_times, player_scores).map_err(|e| {
+        tracing::error!("Error while combining results. Error = [{e:?}]");
 
-            (StatusCode::INTERNAL_SERVER_ERROR).into_response()
-        }
+        StatusCode::INTERNAL_SERVER_ERROR
+    })
+}
+
+pub async fn match_info_handler(
+    State(db): State<Arc<PgPool>>,
+    Json(info): Json<HandlerInfo>,
+) -> impl IntoResponse {
+    match select_match(info.match_id, &db).await {
+        Ok(result) => (StatusCode::OK, Json(result)).into_response(),
+        Err(status) => status.into_response(),
     }
 }
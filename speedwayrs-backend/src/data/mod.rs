@@ -1,6 +1,9 @@
-mod player_data;
+mod fixtures;
+mod gate_stats;
+pub(crate) mod player_data;
 mod main_info;
-mod match_info;
+pub(crate) mod match_info;
+mod search;
 mod team_data;
 mod team_stats;
 
@@ -112,7 +115,13 @@ pub fn data_router() -> Router<AppData> {
         .route("/team_stats", post(team_stats::team_stats))
         .route("/match_info", post(match_info::match_info_handler))
         .route("/last_games", post(main_info::last_games))
+        .route("/fixtures", post(fixtures::fixtures))
+        .route("/search", post(search::search))
+        .route("/leagues", get(main_info::leagues))
         .route("/liked_teams", get(main_info::liked_teams))
         .route("/liked_players", get(main_info::liked_players))
         .route("/player_info", post(player_data::get_player_data))
+        .route("/stadiums", get(gate_stats::stadiums))
+        .route("/player_gate_stats", post(gate_stats::player_gate_stats))
+        .route("/stadium_gate_stats", post(gate_stats::stadium_gate_stats))
 }
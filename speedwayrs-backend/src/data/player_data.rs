@@ -1,9 +1,9 @@
 use axum::{extract::State, response::IntoResponse, Json};
 use http::StatusCode;
 use serde::{Serialize, Deserialize};
-use speedwayrs_types::PlayerResult;
+use speedwayrs_types::{IncidentKind, PlayerResult};
 use sqlx::PgPool;
-use std::sync::Arc;
+use std::{collections::BTreeMap, sync::Arc};
 use axum::Extension;
 use crate::session::AuthStatus;
 
@@ -20,18 +20,21 @@ struct PlayerInfo {
     zero_points: u32,
     stars: u32,
     accidents: u16,
+    // Number of rides without points for every reason.
+    discipline: Vec<(IncidentKind, u16)>,
     former_teams: Vec<(i32, String, u16)>,
     name: String,
     user_like: Option<bool>
 }
 
-struct PlayerStats {
-    three_points: u32,
-    two_points: u32,
-    one_points: u32,
-    zero_points: u32,
-    stars: u32,
-    accidents: u16
+pub(crate) struct PlayerStats {
+    pub(crate) three_points: u32,
+    pub(crate) two_points: u32,
+    pub(crate) one_points: u32,
+    pub(crate) zero_points: u32,
+    pub(crate) stars: u32,
+    pub(crate) accidents: u16,
+    pub(crate) discipline: Vec<(IncidentKind, u16)>
 }
 
 async fn check_player_like(db: &PgPool, username: &str, player_id: i32) -> Result<bool, sqlx::Error> {
@@ -42,7 +45,7 @@ async fn check_player_like(db: &PgPool, username: &str, player_id: i32) -> Resul
     Ok(query.is_some())
 }
 
-async fn get_player_stats(db: &PgPool, id: i32) -> Result<PlayerStats, sqlx::Error> {
+pub(crate) async fn get_player_stats(db: &PgPool, id: i32) -> Result<PlayerStats, sqlx::Error> {
     let query_result = sqlx::query_file!("queries/data/get_player_stats.sql", id)
         .fetch_all(db)
         .await?;
@@ -50,6 +53,7 @@ async fn get_player_stats(db: &PgPool, id: i32) -> Result<PlayerStats, sqlx::Err
     let mut points = [0 ; 4];
     let mut stars = 0;
     let mut accidents = 0;
+    let mut discipline: BTreeMap<IncidentKind, u16> = BTreeMap::new();
 
     for record in query_result {
         match PlayerResult::from_str(&record.result) {
@@ -62,8 +66,12 @@ async fn get_player_stats(db: &PgPool, id: i32) -> Result<PlayerStats, sqlx::Err
                         points[score as usize] += 1;
                         stars += 1;
                     }
-                    _ => {
+                    other => {
                         accidents += 1;
+
+                        if let Some(kind) = other.incident() {
+                            *discipline.entry(kind).or_default() += 1;
+                        }
                     }
                 }
             }
@@ -80,12 +88,13 @@ async fn get_player_stats(db: &PgPool, id: i32) -> Result<PlayerStats, sqlx::Err
             one_points: points[1],
             zero_points: points[0],
             stars,
-            accidents
+            accidents,
+            discipline: discipline.into_iter().collect()
         }
     )
 }
 
-async fn get_former_teams(db: &PgPool, id: i32) -> Result<Vec<(i32, String, u16)>, sqlx::Error> {
+pub(crate) async fn get_former_teams(db: &PgPool, id: i32) -> Result<Vec<(i32, String, u16)>, sqlx::Error> {
     let former_teams = sqlx::query_file!("queries/data/get_player_teams.sql", id)
         .fetch_all(db)
         .await?;
@@ -155,6 +164,7 @@ pub async fn get_player_data(State(db): State<Arc<PgPool>>, Extension(auth_info)
         zero_points: player_stats.zero_points,
         stars: player_stats.stars,
         accidents: player_stats.accidents,
+        discipline: player_stats.discipline,
         former_teams,
         name: player_name,
         user_like: player_like 
@@ -0,0 +1,178 @@
+use std::sync::Arc;
+
+use axum::{extract::State, response::IntoResponse, Json};
+use http::StatusCode;
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+
+use crate::pagination::{PageRequest, Pagination};
+
+#[derive(Deserialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+enum SearchKind {
+    Player,
+    Team,
+    Stadium,
+    Match,
+}
+
+impl SearchKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Player => "player",
+            Self::Team => "team",
+            Self::Stadium => "stadium",
+            Self::Match => "match",
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct SearchRequest {
+    query: String,
+    page: i64,
+    // Every kind is searched if none is given.
+    kinds: Option<Vec<SearchKind>>,
+}
+
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum SearchResult {
+    Player {
+        id: i32,
+        name: String,
+        sname: String,
+    },
+    Team {
+        id: i32,
+        name: String,
+    },
+    Stadium {
+        id: i32,
+        name: String,
+    },
+    Match {
+        id: i32,
+        team1: String,
+        team2: String,
+        date: time::OffsetDateTime,
+        league: String,
+        status: String,
+    },
+}
+
+#[derive(Serialize)]
+struct SearchResponse {
+    pagination: Pagination,
+    results: Vec<SearchResult>,
+}
+
+const PAGE_SIZE: i64 = 20;
+/// Minimal word similarity of found names, low enough to forgive a typo in short names.
+const SIMILARITY_THRESHOLD: f32 = 0.4;
+
+/// Returns pattern of LIKE which matches searched text literally anywhere in name.
+fn like_pattern(text: &str) -> String {
+    let escaped = text
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+
+    format!("%{escaped}%")
+}
+
+/// Returns number of all found results along with results on requested page.
+async fn find(
+    db: &PgPool,
+    text: &str,
+    kinds: Option<&[String]>,
+    page: &PageRequest,
+) -> Result<(i64, Vec<SearchResult>), sqlx::Error> {
+    // Threshold of similarity operator is a setting, so it is set only for this transaction.
+    let mut transaction = db.begin().await?;
+
+    sqlx::query_file!(
+        "queries/data/search_threshold.sql",
+        SIMILARITY_THRESHOLD.to_string()
+    )
+    .fetch_one(&mut transaction)
+    .await?;
+
+    let rows = sqlx::query_file!(
+        "queries/data/search.sql",
+        text,
+        kinds,
+        page.limit(),
+        page.offset(),
+        like_pattern(text)
+    )
+    .fetch_all(&mut transaction)
+    .await?;
+
+    transaction.commit().await?;
+
+    let total = rows.first().map_or(0, |row| row.total);
+    let results = rows
+        .into_iter()
+        .filter_map(|row| match (row.kind?.as_str(), row.id?, row.name?) {
+            ("player", id, name) => Some(SearchResult::Player {
+                id,
+                name,
+                sname: row.sname?,
+            }),
+            ("team", id, name) => Some(SearchResult::Team { id, name }),
+            ("stadium", id, name) => Some(SearchResult::Stadium { id, name }),
+            ("match", id, team1) => Some(SearchResult::Match {
+                id,
+                team1,
+                team2: row.team2?,
+                date: row.date?,
+                league: row.league?,
+                status: row.status?,
+            }),
+            _ => None,
+        })
+        .collect();
+
+    Ok((total, results))
+}
+
+/// Searches players, teams, stadiums and games by name, ignoring case and diacritics.
+/// Results are ordered from the most similar ones.
+pub async fn search(
+    State(db): State<Arc<PgPool>>,
+    Json(request): Json<SearchRequest>,
+) -> impl IntoResponse {
+    let text = request.query.trim();
+
+    let Some(page) = PageRequest::new(Some(request.page), Some(PAGE_SIZE)) else {
+        return (StatusCode::BAD_REQUEST).into_response();
+    };
+
+    if text.is_empty() {
+        return (StatusCode::BAD_REQUEST).into_response();
+    }
+
+    let kinds = request.kinds.map(|kinds| {
+        kinds
+            .iter()
+            .map(|kind| kind.as_str().to_string())
+            .collect::<Vec<_>>()
+    });
+
+    match find(db.as_ref(), text, kinds.as_deref(), &page).await {
+        Err(e) => {
+            tracing::error!("Error returned while searching. Error = [{e:?}]");
+
+            (StatusCode::INTERNAL_SERVER_ERROR).into_response()
+        }
+        Ok((total, results)) => {
+            let response = SearchResponse {
+                pagination: page.pagination(total),
+                results,
+            };
+
+            (StatusCode::OK, Json(response)).into_response()
+        }
+    }
+}
@@ -1,5 +1,7 @@
 mod account;
+mod api;
 mod data;
+mod pagination;
 mod session;
 mod utils;
 
@@ -62,6 +64,8 @@ async fn main() -> Result<()> {
             app_data.clone(),
             session::session_management,
         ))
+        // Public API does not use sessions, so it is nested after their middleware.
+        .nest("/api/v1", api::api_router())
         .with_state(app_data)
         .layer(
             tower_http::cors::CorsLayer::very_permissive()
@@ -0,0 +1,48 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+const DEFAULT_PER_PAGE: i64 = 20;
+const MAX_PER_PAGE: i64 = 100;
+
+/// Position of returned page among all results.
+#[derive(Serialize, ToSchema)]
+pub(crate) struct Pagination {
+    /// Number of page, counted from 1.
+    page: i64,
+    per_page: i64,
+    /// Number of results on all pages.
+    total: i64,
+    pages: i64,
+}
+
+pub(crate) struct PageRequest {
+    page: i64,
+    per_page: i64,
+}
+
+impl PageRequest {
+    /// Returns None if page or its size are out of range.
+    pub(crate) fn new(page: Option<i64>, per_page: Option<i64>) -> Option<Self> {
+        let page = page.unwrap_or(1);
+        let per_page = per_page.unwrap_or(DEFAULT_PER_PAGE);
+
+        (page >= 1 && (1..=MAX_PER_PAGE).contains(&per_page)).then_some(Self { page, per_page })
+    }
+
+    pub(crate) fn limit(&self) -> i64 {
+        self.per_page
+    }
+
+    pub(crate) fn offset(&self) -> i64 {
+        (self.page - 1).saturating_mul(self.per_page)
+    }
+
+    pub(crate) fn pagination(&self, total: i64) -> Pagination {
+        Pagination {
+            page: self.page,
+            per_page: self.per_page,
+            total,
+            pages: (total + self.per_page - 1) / self.per_page,
+        }
+    }
+}
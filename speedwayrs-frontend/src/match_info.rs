@@ -37,12 +37,39 @@ async fn retrieve_match_info(match_id: i32, place: &Signal<Option<MatchResult>>)
 fn generate_score(info: &Signal<Option<MatchResult>>) -> String {
     match info.get().as_ref() {
         None => "".into(),
+        // Games which were not played yet have no score.
+        Some(info) if !info.status().is_played() => info.status().to_pretty().into(),
         Some(info) => {
             format!("{} : {}", info.first_team_score(), info.second_team_score())
         }
     }
 }
 
+fn generate_details(info: &Signal<Option<MatchResult>>) -> String {
+    let Some(info) = info.get().as_ref().as_ref().map(|info| info.details().clone()) else {
+        return "".into();
+    };
+
+    let mut parts = Vec::new();
+
+    match (info.stage(), info.round()) {
+        (_, Some(round)) => parts.push(format!("Runda {round}")),
+        (Some(stage), None) => parts.push(stage.to_pretty().into()),
+        (None, None) => {}
+    }
+    if let Some(referee) = info.referee() {
+        parts.push(format!("Sędzia: {referee}"));
+    }
+    if let Some(commissioner) = info.track_commissioner() {
+        parts.push(format!("Komisarz toru: {commissioner}"));
+    }
+    if let Some(attendance) = info.attendance() {
+        parts.push(format!("Widzów: {attendance}"));
+    }
+
+    parts.join(" | ")
+}
+
 #[derive(Prop)]
 struct RunResultInfo<'a, G: Html> {
     info: RunInfo,
@@ -56,15 +83,29 @@ fn RunResult<'a, G: Html>(cx: Scope<'a>, run_info: RunResultInfo<'a, G>) -> View
         info_clone
             .scores()
             .iter()
-            .map(|(player_id, name, score)| {
-                let id = *player_id;
-                let name = name.clone();
-                let parsed_score = match PlayerResult::from_str(score) {
+            .map(|score| {
+                let id = score.player_id();
+                let name = match score.substitution() {
+                    None => score.name().to_string(),
+                    Some(substitution) => match substitution.replaced_player() {
+                        Some((_, replaced)) => format!(
+                            "{} ({} za {replaced})",
+                            score.name(),
+                            substitution.kind().to_pretty()
+                        ),
+                        None => format!("{} ({})", score.name(), substitution.kind().to_pretty()),
+                    },
+                };
+                let parsed_score = match PlayerResult::from_str(score.score()) {
                     Some(score) => score.to_pretty(),
                     None => "Error.".into(),
                 };
+                let gate = match score.helmet() {
+                    Some(helmet) => format!("{} ({})", helmet.gate(), helmet.to_pretty()),
+                    None => "".into(),
+                };
 
-                (id, name, parsed_score)
+                (id, name, parsed_score, gate)
             })
             .collect()
     });
@@ -87,6 +128,9 @@ fn RunResult<'a, G: Html>(cx: Scope<'a>, run_info: RunResultInfo<'a, G>) -> View
             table(class="w-full h-full border-separate border-spacing-2 text-center") {
                 thead() {
                     tr() {
+                        th(class="border border-2 rounded-md bg-indigo-600/40 border-indigo-900/50") {
+                            "Pole"
+                        }
                         th(class="border border-2 rounded-md bg-indigo-600/40 border-indigo-900/50") {
                             "Zawodnik"
                         }
@@ -101,6 +145,9 @@ fn RunResult<'a, G: Html>(cx: Scope<'a>, run_info: RunResultInfo<'a, G>) -> View
                         view = |cx, to_view| view! {
                             cx,
                             tr() {
+                                td(class="border border-2 rounded-md border-indigo-300/50") {
+                                    (to_view.3)
+                                }
                                 td(class="border border-2 rounded-md border-indigo-300/50") {
                                     a(class="hover:text-green-700", href=format!("/player/{}", to_view.0)) {
                                         (to_view.1)
@@ -165,6 +212,9 @@ pub fn MatchInfo<'a, G: Html>(cx: Scope<'a>, match_info: MatchInfoParams) -> Vie
                         (second_team_name.get())
                     }
                 }
+                p(class="pt-3 text-center") {
+                    (generate_details(info_signal))
+                }
             }
             Indexed(
                 iterable=run_infos,
@@ -2,6 +2,7 @@ use std::rc::Rc;
 
 use log::info;
 use serde::Deserialize;
+use speedwayrs_types::IncidentKind;
 use sycamore::{
     futures::spawn_local_scoped,
     prelude::Indexed,
@@ -191,6 +192,7 @@ struct PlayerInfo {
     zero_points: u32,
     stars: u32,
     accidents: u32,
+    discipline: Vec<(IncidentKind, u32)>,
     former_teams: Vec<(i32, String, u32)>,
     name: String,
     user_like: Option<bool>
@@ -256,6 +258,15 @@ pub fn PlayerPage<'a, G: Html>(cx: Scope<'a>, props: PlayerPageProps<'a>) -> Vie
         format!("{:.3}", mean)
     });
 
+    let iterable_discipline = create_selector(cx, || {
+        match player_info.get().as_ref() {
+            None => Vec::new(),
+            Some(info) => {
+                info.discipline.clone()
+            }
+        }
+    });
+
     let iterable_history = create_selector(cx, || {
         match player_info.get().as_ref() {
             None => Vec::new(),
@@ -343,6 +354,20 @@ pub fn PlayerPage<'a, G: Html>(cx: Scope<'a>, props: PlayerPageProps<'a>) -> Vie
                                             (info.accidents)
                                         }
                                     }
+                                    Indexed(
+                                        iterable=iterable_discipline,
+                                        view = |cx, (kind, count)| view! {
+                                            cx,
+                                            tr() {
+                                                td(class=DESC_CSS) {
+                                                    (kind.to_pretty())
+                                                }
+                                                td(class=VAL_CSS) {
+                                                    (count)
+                                                }
+                                            }
+                                        }
+                                    )
                                     tr() {
                                         td(class=DESC_CSS) {
                                             "Średnia liczba zdobytych punktów"
@@ -0,0 +1 @@
+SELECT application.merge_players($1, $2) AS "player_id!";
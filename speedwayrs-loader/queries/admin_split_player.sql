@@ -0,0 +1 @@
+SELECT application.split_player($1, $2, $3) AS "player_id!";
@@ -0,0 +1,2 @@
+DELETE FROM application.run
+WHERE game_id = $1;
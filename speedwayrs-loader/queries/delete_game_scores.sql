@@ -0,0 +1,2 @@
+DELETE FROM application.player_score
+WHERE game_id = $1;
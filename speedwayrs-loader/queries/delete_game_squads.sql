@@ -0,0 +1,2 @@
+DELETE FROM application.squad
+WHERE run IN (SELECT id FROM application.run WHERE game_id = $1);
@@ -0,0 +1,2 @@
+DELETE FROM application.game_team
+WHERE game = $1;
@@ -1,3 +1,21 @@
-INSERT INTO application.game (team_1, score_1, score_2, team_2, place, game_date)
-VALUES ($1, $2, $3, $4, $5, $6)
-RETURNING game_id;
+INSERT INTO application.game (
+  team_1, score_1, score_2, team_2, place, game_date, league,
+  round_number, stage, referee, track_commissioner, attendance, source_url, content_hash, status
+)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, md5($14), $15)
+ON CONFLICT ON CONSTRAINT game_natural_key DO UPDATE
+SET
+  score_1 = EXCLUDED.score_1,
+  score_2 = EXCLUDED.score_2,
+  place = EXCLUDED.place,
+  round_number = EXCLUDED.round_number,
+  stage = EXCLUDED.stage,
+  referee = EXCLUDED.referee,
+  track_commissioner = EXCLUDED.track_commissioner,
+  attendance = EXCLUDED.attendance,
+  source_url = COALESCE(EXCLUDED.source_url, application.game.source_url),
+  content_hash = EXCLUDED.content_hash,
+  status = EXCLUDED.status
+WHERE application.game.content_hash IS DISTINCT FROM EXCLUDED.content_hash
+-- Only rows updated on conflict have xmax set.
+RETURNING game_id, xmax = 0 AS "inserted!";
@@ -1,2 +0,0 @@
-INSERT INTO application.game_team (player, team, game)
-VALUES ($1, $2, $3);
@@ -0,0 +1,3 @@
+INSERT INTO application.game_team (player, team, game)
+SELECT player, team, $3
+FROM UNNEST($1::INTEGER[], $2::INTEGER[]) AS lineup (player, team);
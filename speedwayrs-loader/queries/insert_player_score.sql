@@ -1,2 +0,0 @@
-INSERT INTO application.player_score (game_id, player_id, round, score)
-VALUES ($1, $2, $3, $4);
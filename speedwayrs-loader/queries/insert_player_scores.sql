@@ -0,0 +1,3 @@
+INSERT INTO application.player_score (game_id, player_id, round, score)
+SELECT $1, player_id, round, score
+FROM UNNEST($2::INTEGER[], $3::INTEGER[], $4::VARCHAR[]) AS scores (player_id, round, score);
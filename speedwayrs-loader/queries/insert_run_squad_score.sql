@@ -1,2 +0,0 @@
-INSERT INTO application.squad (run, player_id, result, color)
-VALUES ($1, $2, $3, NULL);
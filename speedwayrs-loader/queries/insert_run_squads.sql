@@ -0,0 +1,5 @@
+INSERT INTO application.squad (run, player_id, result, color, substitution, replaced_player)
+SELECT *
+FROM UNNEST(
+  $1::BIGINT[], $2::INTEGER[], $3::VARCHAR[], $4::VARCHAR[], $5::VARCHAR[], $6::INTEGER[]
+);
@@ -0,0 +1,4 @@
+INSERT INTO application.run (run_position, time_integer, time_decimal, game_id)
+SELECT run_position, time_integer, time_decimal, $4
+FROM UNNEST($1::INTEGER[], $2::INTEGER[], $3::INTEGER[]) AS runs (run_position, time_integer, time_decimal)
+RETURNING id, run_position;
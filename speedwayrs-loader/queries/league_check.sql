@@ -0,0 +1,6 @@
+SELECT
+  league_id
+FROM
+  application.league
+WHERE
+  league_name = $1;
@@ -0,0 +1,3 @@
+INSERT INTO application.league (league_name)
+VALUES ($1)
+RETURNING league_id;
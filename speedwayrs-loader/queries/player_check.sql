@@ -1,6 +1,14 @@
 SELECT
-  player.player_id
-FROM
-  application.player
-WHERE
-  player.name = $1 AND player.sname = $2;
+  candidate.player_id AS "player_id!"
+FROM (
+  SELECT player.player_id, CASE WHEN player.source_url IS NULL THEN 0 ELSE 2 END AS priority
+  FROM application.player
+  WHERE player.name = $1 AND player.sname = $2
+  UNION ALL
+  SELECT player_alias.player_id, 1 AS priority
+  FROM application.player_alias
+  WHERE player_alias.name = $1 AND player_alias.sname = $2
+) AS candidate
+ORDER BY
+  candidate.priority, candidate.player_id
+LIMIT 1;
@@ -0,0 +1,14 @@
+SELECT
+  player.player_id AS "player_id!"
+FROM
+  application.player
+WHERE
+  player.source_url = $1
+UNION ALL
+SELECT
+  player_alias.player_id
+FROM
+  application.player_alias
+WHERE
+  player_alias.source_url = $1
+LIMIT 1;
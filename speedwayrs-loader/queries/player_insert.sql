@@ -1,3 +1,3 @@
-INSERT INTO application.player (name, sname)
-VALUES ($1, $2)
+INSERT INTO application.player (name, sname, source_url)
+VALUES ($1, $2, $3)
 RETURNING player_id;
@@ -0,0 +1,18 @@
+UPDATE application.game
+SET
+  team_1 = $1,
+  score_1 = $2,
+  score_2 = $3,
+  team_2 = $4,
+  place = $5,
+  game_date = $6,
+  league = $7,
+  round_number = $8,
+  stage = $9,
+  referee = $10,
+  track_commissioner = $11,
+  attendance = $12,
+  content_hash = md5($14),
+  status = $15
+WHERE source_url = $13 AND content_hash IS DISTINCT FROM md5($14)
+RETURNING game_id, FALSE AS "inserted!";
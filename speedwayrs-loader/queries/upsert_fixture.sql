@@ -0,0 +1,26 @@
+-- Fixtures are found by their site. Games which are already played are left as they are.
+INSERT INTO application.game (team_1, team_2, game_date, league, status, source_url)
+VALUES ($1, $2, $3, $4, $5, $6)
+ON CONFLICT (source_url) DO UPDATE
+SET
+  team_1 = EXCLUDED.team_1,
+  team_2 = EXCLUDED.team_2,
+  game_date = EXCLUDED.game_date,
+  league = EXCLUDED.league,
+  status = EXCLUDED.status
+WHERE
+  application.game.status NOT IN ('live', 'finished')
+  AND (
+    application.game.team_1,
+    application.game.team_2,
+    application.game.game_date,
+    application.game.league,
+    application.game.status
+  ) IS DISTINCT FROM (
+    EXCLUDED.team_1,
+    EXCLUDED.team_2,
+    EXCLUDED.game_date,
+    EXCLUDED.league,
+    EXCLUDED.status
+  )
+RETURNING game_id, xmax = 0 AS "inserted!";
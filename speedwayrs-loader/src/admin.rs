@@ -0,0 +1,26 @@
+use sqlx::PgPool;
+
+/// Merges rider `removed` into rider `kept`. Name and profile site of removed rider
+/// become aliases of kept one, so they are resolved to him by next loads.
+pub async fn merge_players(db: &PgPool, kept: i32, removed: i32) -> Result<(), sqlx::Error> {
+    sqlx::query_file!("queries/admin_merge_players.sql", kept, removed)
+        .fetch_one(db)
+        .await?;
+
+    Ok(())
+}
+
+/// Moves rides of rider `original` in given games to a new rider with given profile site.
+/// Returns identifier of the new rider.
+pub async fn split_player(
+    db: &PgPool,
+    original: i32,
+    games: &[i32],
+    source_url: &str,
+) -> Result<i32, sqlx::Error> {
+    let record = sqlx::query_file!("queries/admin_split_player.sql", original, games, source_url)
+        .fetch_one(db)
+        .await?;
+
+    Ok(record.player_id)
+}
@@ -0,0 +1,75 @@
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand};
+
+#[derive(Parser, Debug)]
+#[clap(
+    author,
+    version,
+    about = "Loads games scraped by speedwayrs-scraper into database.",
+    args_conflicts_with_subcommands = true
+)]
+pub struct ProgramConfig {
+    /// Postgres connection string.
+    #[arg(long, env = "LOADER_POSTGRES", value_name = "URL", global = true)]
+    database_url: Option<String>,
+
+    /// Checks consistency of games without connecting to database.
+    #[arg(long)]
+    check: bool,
+
+    /// Files with scraped games. Standard input is read if no file is given or file is `-`.
+    #[arg(value_name = "FILE")]
+    files: Vec<PathBuf>,
+
+    #[command(subcommand)]
+    command: Option<AdminCommand>,
+}
+
+/// Corrections of riders identified wrongly by previous loads.
+#[derive(Subcommand, Debug)]
+pub enum AdminCommand {
+    /// Moves every record of removed rider to kept rider.
+    Merge {
+        /// Id of rider who is kept.
+        kept: i32,
+        /// Id of rider who is removed.
+        removed: i32,
+    },
+    /// Moves rides of rider in given games to a new rider.
+    Split {
+        /// Id of split rider.
+        player: i32,
+        /// Profile site of the new rider.
+        source_url: String,
+        /// Games in which the new rider rode.
+        #[arg(required = true)]
+        games: Vec<i32>,
+    },
+}
+
+impl ProgramConfig {
+    /// Returns connection string given as argument or in environment.
+    pub fn database_url(&self) -> Option<&str> {
+        self.database_url.as_deref()
+    }
+
+    /// Returns true if games should be only checked.
+    pub fn check(&self) -> bool {
+        self.check
+    }
+
+    /// Returns files to load. Standard input is given as `-`.
+    pub fn files(&self) -> Vec<PathBuf> {
+        if self.files.is_empty() {
+            vec![PathBuf::from("-")]
+        } else {
+            self.files.clone()
+        }
+    }
+
+    /// Returns admin operation to run instead of loading games.
+    pub fn command(&self) -> Option<&AdminCommand> {
+        self.command.as_ref()
+    }
+}
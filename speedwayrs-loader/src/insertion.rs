@@ -1,40 +1,71 @@
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{Arc, Mutex},
+};
+
+use speedwayrs_types::scraper_types::{Fixture, GameInfo, Player, Team};
+use sqlx::{Connection, PgConnection, PgPool};
+
+fn is_unique_violation(error: &sqlx::Error) -> bool {
+    error
+        .as_database_error()
+        .and_then(|db_error| db_error.code())
+        .is_some_and(|code| code == "23505")
+}
+
+/// Finds rider by his profile site, or by name if file does not contain it.
+/// Rider loaded earlier without profile site is not linked to a site with his name, as
+/// it may belong to another rider. Such riders are joined by `admin::merge_players`.
+async fn check_player(player: &Player, db: &PgPool) -> Result<i32, sqlx::Error> {
+    let (name, sname) = (player.name(), player.surname());
 
-use crate::scraper_types::{GameInfo, Player, Team};
-use speedwayrs_types::PlayerResult;
-use sqlx::{Executor, PgExecutor, PgPool};
+    let Some(source_url) = player.source_url() else {
+        let possible_id = sqlx::query_file!("queries/player_check.sql", name, sname)
+            .fetch_optional(db)
+            .await?;
 
-async fn check_player(name: &str, sname: &str, db: &PgPool) -> Result<i32, sqlx::Error> {
-    let possible_id = sqlx::query_file!("queries/player_check.sql", name, sname)
+        if let Some(id) = possible_id {
+            return Ok(id.player_id);
+        }
+
+        return match sqlx::query_file!("queries/player_insert.sql", name, sname, None::<&str>)
+            .fetch_one(db)
+            .await
+        {
+            Ok(record) => Ok(record.player_id),
+            Err(e) if is_unique_violation(&e) => {
+                let id = sqlx::query_file!("queries/player_check.sql", name, sname)
+                    .fetch_one(db)
+                    .await?;
+
+                Ok(id.player_id)
+            }
+            Err(e) => Err(e),
+        };
+    };
+
+    let possible_id = sqlx::query_file!("queries/player_check_url.sql", source_url)
         .fetch_optional(db)
         .await?;
 
-    match possible_id {
-        Some(id) => Ok(id.player_id),
-        None => {
-            let insert_stmt = sqlx::query_file!("queries/player_insert.sql", name, sname)
-                .fetch_one(db)
-                .await;
-
-            match insert_stmt {
-                Ok(record) => Ok(record.player_id),
-                Err(e) => {
-                    if let Some(db_error) = e.as_database_error() {
-                        if let Some(error_string) = db_error.code() {
-                            if error_string.as_bytes() == "23505".as_bytes() {
-                                let id = sqlx::query_file!("queries/player_check.sql", name, sname)
-                                    .fetch_one(db)
-                                    .await?;
+    if let Some(id) = possible_id {
+        return Ok(id.player_id);
+    }
 
-                                return Ok(id.player_id);
-                            }
-                        }
-                    }
+    match sqlx::query_file!("queries/player_insert.sql", name, sname, Some(source_url))
+        .fetch_one(db)
+        .await
+    {
+        Ok(record) => Ok(record.player_id),
+        Err(e) if is_unique_violation(&e) => {
+            let id = sqlx::query_file!("queries/player_check_url.sql", source_url)
+                .fetch_one(db)
+                .await?;
 
-                    Err(e)
-                }
-            }
+            Ok(id.player_id)
         }
+        Err(e) => Err(e),
     }
 }
 
@@ -120,199 +151,520 @@ async fn check_place(description: &str, db: &PgPool) -> Result<i32, sqlx::Error>
     }
 }
 
-async fn map_players<'a>(
-    players: &'a [Player],
-    db: &Arc<PgPool>,
-) -> Result<HashMap<(&'a str, &'a str), i32>, sqlx::Error> {
+async fn check_league(name: &str, db: &PgPool) -> Result<i32, sqlx::Error> {
+    let possible_id = sqlx::query_file!("queries/league_check.sql", name)
+        .fetch_optional(db)
+        .await?;
+
+    match possible_id {
+        Some(league) => Ok(league.league_id),
+        None => {
+            let insert_stmt = sqlx::query_file!("queries/league_insert.sql", name)
+                .fetch_one(db)
+                .await;
+
+            match insert_stmt {
+                Ok(record) => Ok(record.league_id),
+                Err(e) => {
+                    if let Some(db_error) = e.as_database_error() {
+                        if let Some(error_string) = db_error.code() {
+                            if error_string.as_bytes() == "23505".as_bytes() {
+                                let id = sqlx::query_file!("queries/league_check.sql", name)
+                                    .fetch_one(db)
+                                    .await?;
+
+                                return Ok(id.league_id);
+                            }
+                        }
+                    }
+
+                    Err(e)
+                }
+            }
+        }
+    }
+}
+
+/// Identifiers of teams, stadiums, leagues and riders found during this run,
+/// so every one of them is looked up in database only once.
+#[derive(Default)]
+pub struct IdCache {
+    teams: Mutex<HashMap<String, i32>>,
+    places: Mutex<HashMap<String, i32>>,
+    leagues: Mutex<HashMap<String, i32>>,
+    // Riders are keyed by their profile site, or by full name if it is unknown.
+    players: Mutex<HashMap<String, i32>>,
+}
+
+async fn cached<F>(map: &Mutex<HashMap<String, i32>>, key: &str, lookup: F) -> Result<i32, sqlx::Error>
+where
+    F: Future<Output = Result<i32, sqlx::Error>>,
+{
+    if let Some(id) = map.lock().unwrap().get(key) {
+        return Ok(*id);
+    }
+
+    let id = lookup.await?;
+    map.lock().unwrap().insert(key.to_string(), id);
+
+    Ok(id)
+}
+
+/// Maps full names of riders to their identifiers.
+/// Names are unique inside a game, so they are enough to find riders of every run.
+async fn map_players(
+    players: &[Player],
+    cache: &IdCache,
+    db: &PgPool,
+) -> Result<HashMap<String, i32>, sqlx::Error> {
     let mut map = HashMap::new();
 
     for player in players {
-        let id = check_player(player.name(), player.surname(), db).await?;
+        let full_name = player.full_name();
+        let key = player.source_url().unwrap_or(&full_name);
+        let id = cached(&cache.players, key, check_player(player, db)).await?;
 
-        map.insert((player.name().into(), player.surname().into()), id);
+        map.insert(full_name, id);
     }
 
     Ok(map)
 }
 
-async fn map_to_ids<'a, 'b>(
-    team1: &'a Team,
-    team2: &'b Team,
-    db: &Arc<PgPool>,
-) -> Result<
-    (
-        HashMap<(&'a str, &'a str), i32>,
-        HashMap<(&'b str, &'b str), i32>,
-    ),
-    sqlx::Error,
-> {
-    Ok((
-        map_players(team1.players(), db).await?,
-        map_players(team2.players(), db).await?,
-    ))
+/// Outcome of loading a single game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Insertion {
+    Inserted,
+    Updated,
+    /// Game was loaded before from the same record.
+    Unchanged,
 }
 
-async fn insert_game<'a, T: PgExecutor<'a>>(
+/// Inserts game or updates game with the same teams, date and league.
+/// Game moved to another date is found by its site.
+/// Returns id of game and whether it was inserted, or `None` if stored game is up to date.
+async fn upsert_game(
     payload: &GameInfo,
     team1_id: i32,
     team2_id: i32,
     stadium: i32,
-    db: T,
-) -> Result<i32, sqlx::Error> {
+    league: i32,
+    db: &mut PgConnection,
+) -> Result<Option<(i32, bool)>, sqlx::Error> {
+    let content = serde_json::to_string(payload).unwrap();
     let date = payload.date().assume_utc();
+    let details = payload.details();
+    let score_1 = payload.team_one().score() as i32;
+    let score_2 = payload.team_two().score() as i32;
+    let round = details.round().map(i16::from);
+    let stage = details.stage().map(|stage| stage.as_str());
+    let attendance = details.attendance().map(|attendance| attendance as i32);
+    let status = payload.status().as_str();
+
+    if let Some(source_url) = payload.source_url() {
+        let updated = sqlx::query_file!(
+            "queries/update_game_by_url.sql",
+            team1_id,
+            score_1,
+            score_2,
+            team2_id,
+            stadium,
+            date,
+            league,
+            round,
+            stage,
+            details.referee(),
+            details.track_commissioner(),
+            attendance,
+            source_url,
+            content,
+            status
+        )
+        .fetch_optional(&mut *db)
+        .await?;
 
-    let insert_result = sqlx::query_file!(
+        if let Some(record) = updated {
+            return Ok(Some((record.game_id, record.inserted)));
+        }
+    }
+
+    let record = sqlx::query_file!(
         "queries/insert_game.sql",
         team1_id,
-        payload.team_one().score() as i32,
-        payload.team_two().score() as i32,
+        score_1,
+        score_2,
         team2_id,
         stadium,
-        date
+        date,
+        league,
+        round,
+        stage,
+        details.referee(),
+        details.track_commissioner(),
+        attendance,
+        payload.source_url(),
+        content,
+        status
     )
-    .fetch_one(db)
+    .fetch_optional(&mut *db)
     .await?;
 
-    Ok(insert_result.game_id)
+    Ok(record.map(|record| (record.game_id, record.inserted)))
 }
 
-async fn insert_player_score<'a, T: PgExecutor<'a>>(
-    player: i32,
+/// Removes runs, squads and scores of game, so they can be inserted again.
+async fn clear_game(game: i32, db: &mut PgConnection) -> Result<(), sqlx::Error> {
+    sqlx::query_file!("queries/delete_game_squads.sql", game)
+        .execute(&mut *db)
+        .await?;
+    sqlx::query_file!("queries/delete_game_runs.sql", game)
+        .execute(&mut *db)
+        .await?;
+    sqlx::query_file!("queries/delete_game_scores.sql", game)
+        .execute(&mut *db)
+        .await?;
+    sqlx::query_file!("queries/delete_game_teams.sql", game)
+        .execute(&mut *db)
+        .await?;
+
+    Ok(())
+}
+
+/// Riders of lineup, without placeholders of empty places.
+fn lineup(team: &Team) -> impl Iterator<Item = &Player> {
+    team.players().iter().filter(|player| {
+        !player.name().eq_ignore_ascii_case("brak")
+            || !player.surname().eq_ignore_ascii_case("zawodnika")
+    })
+}
+
+async fn insert_lineups(
+    payload: &GameInfo,
+    team_ids: (i32, i32),
+    players: &HashMap<String, i32>,
     game: i32,
-    round: i32,
-    score: &PlayerResult,
-    db: T,
+    db: &mut PgConnection,
 ) -> Result<(), sqlx::Error> {
-    let score_str = score.to_string();
+    let mut team_players = Vec::new();
+    let mut teams = Vec::new();
+
+    let mut score_players = Vec::new();
+    let mut rounds = Vec::new();
+    let mut scores = Vec::new();
+
+    let lineups = lineup(payload.team_one())
+        .map(|player| (player, team_ids.0))
+        .chain(lineup(payload.team_two()).map(|player| (player, team_ids.1)));
+
+    for (player, team) in lineups {
+        let id = players[&player.full_name()];
+
+        team_players.push(id);
+        teams.push(team);
+
+        for score in player.scores() {
+            score_players.push(id);
+            rounds.push(rounds.len() as i32);
+            scores.push(score.to_string());
+        }
+    }
+
+    sqlx::query_file!("queries/insert_game_teams.sql", &team_players, &teams, game)
+        .execute(&mut *db)
+        .await?;
 
     sqlx::query_file!(
-        "queries/insert_player_score.sql",
+        "queries/insert_player_scores.sql",
         game,
-        player,
-        round,
-        score_str
+        &score_players,
+        &rounds,
+        &scores
     )
-    .execute(db)
+    .execute(&mut *db)
     .await?;
 
     Ok(())
 }
 
-async fn create_run<'a, T: PgExecutor<'a>>(
-    position: i32,
-    time_integer: Option<i32>,
-    time_decimal: Option<i32>,
-    game_id: i32,
-    db: T,
-) -> Result<i64, sqlx::Error> {
-    let query_result = sqlx::query_file!(
-        "queries/create_run.sql",
-        position,
-        time_integer,
-        time_decimal,
-        game_id
+/// Inserts runs with results of riders. Returns number of rides of riders who are not in
+/// any lineup, which cannot be stored.
+async fn insert_runs(
+    payload: &GameInfo,
+    players: &HashMap<String, i32>,
+    game: i32,
+    db: &mut PgConnection,
+) -> Result<usize, sqlx::Error> {
+    let (positions, (times_int, times_dec)): (Vec<_>, (Vec<_>, Vec<_>)) = payload
+        .runs()
+        .iter()
+        .map(|run| {
+            let time = run.time();
+
+            (
+                run.number() as i32,
+                (
+                    time.map(|(seconds, _)| seconds as i32),
+                    time.map(|(_, hundredths)| hundredths as i32),
+                ),
+            )
+        })
+        .unzip();
+
+    let run_ids: HashMap<i32, i64> = sqlx::query_file!(
+        "queries/insert_runs.sql",
+        &positions,
+        &times_int as &[Option<i32>],
+        &times_dec as &[Option<i32>],
+        game
     )
-    .fetch_one(db)
-    .await?;
+    .fetch_all(&mut *db)
+    .await?
+    .into_iter()
+    .map(|record| (record.run_position, record.id))
+    .collect();
+
+    let mut runs = Vec::new();
+    let mut squad_players = Vec::new();
+    let mut results = Vec::new();
+    let mut helmets = Vec::new();
+    let mut substitutions = Vec::new();
+    let mut replaced_players = Vec::new();
+    let mut missing_riders = Vec::new();
 
-    Ok(query_result.id)
-}
+    for run in payload.runs() {
+        let run_id = run_ids[&(run.number() as i32)];
+
+        for score in run.player_scores() {
+            let Some(player_id) = players.get(score.name()) else {
+                missing_riders.push(format!("{} (run {})", score.name(), run.number()));
+                continue;
+            };
+
+            let substitution = score.substitution();
+
+            runs.push(run_id);
+            squad_players.push(*player_id);
+            results.push(score.score().to_string());
+            helmets.push(score.helmet().map(|helmet| helmet.as_str()));
+            substitutions.push(substitution.map(|substitution| substitution.kind().as_str()));
+            replaced_players.push(
+                substitution
+                    .and_then(|substitution| substitution.replaced())
+                    .and_then(|name| players.get(name))
+                    .copied(),
+            );
+        }
+    }
 
-async fn insert_run_squad_score<'a, T: PgExecutor<'a>>(
-    run: i64,
-    player: i32,
-    result: &PlayerResult,
-    db: T,
-) -> Result<(), sqlx::Error> {
     sqlx::query_file!(
-        "queries/insert_run_squad_score.sql",
-        run,
-        player,
-        result.to_string()
+        "queries/insert_run_squads.sql",
+        &runs,
+        &squad_players,
+        &results,
+        &helmets as &[Option<&str>],
+        &substitutions as &[Option<&str>],
+        &replaced_players as &[Option<i32>]
     )
-    .execute(db)
+    .execute(&mut *db)
     .await?;
 
-    Ok(())
-}
+    if !missing_riders.is_empty() {
+        eprintln!(
+            "Rides of riders who are not in any lineup of game {} - {} ({}) were not loaded: {}.",
+            payload.team_one().name(),
+            payload.team_two().name(),
+            payload.date(),
+            missing_riders.join(", ")
+        );
+    }
 
-async fn insert_player_game_team<'a, T: PgExecutor<'a>>(db: T, player: i32, team: i32, game: i32) -> Result<(), sqlx::Error> {
-    let _ = sqlx::query_file!("queries/insert_game_team.sql", player, team, game)
-        .execute(db)
-        .await?;
+    Ok(missing_riders.len())
+}
 
-    Ok(())
+/// Identifiers of teams, stadium, league and riders of a game.
+struct GameIds {
+    teams: (i32, i32),
+    stadium: i32,
+    league: i32,
+    players: HashMap<String, i32>,
 }
 
-pub async fn insert_into_database(db: Arc<PgPool>, payload: GameInfo) -> Result<(), sqlx::Error> {
-    let team_1_id = check_team(payload.team_one().name(), &db).await?;
-    let team_2_id = check_team(payload.team_two().name(), &db).await?;
+async fn resolve_ids(payload: &GameInfo, cache: &IdCache, db: &PgPool) -> Result<GameIds, sqlx::Error> {
+    let team_1_id = cached(
+        &cache.teams,
+        payload.team_one().name(),
+        check_team(payload.team_one().name(), db),
+    )
+    .await?;
+    let team_2_id = cached(
+        &cache.teams,
+        payload.team_two().name(),
+        check_team(payload.team_two().name(), db),
+    )
+    .await?;
+
+    let stadium = cached(&cache.places, payload.place(), check_place(payload.place(), db)).await?;
+    let league = cached(&cache.leagues, payload.league(), check_league(payload.league(), db)).await?;
 
-    let stadium = check_place(payload.place(), &db).await?;
+    let mut players = map_players(payload.team_one().players(), cache, db).await?;
+    players.extend(map_players(payload.team_two().players(), cache, db).await?);
 
-    eprintln!("Initial checking done.");
+    Ok(GameIds {
+        teams: (team_1_id, team_2_id),
+        stadium,
+        league,
+        players,
+    })
+}
 
-    let (mut main_hash_map, id2) = map_to_ids(payload.team_one(), payload.team_two(), &db).await?;
+/// Returns outcome of loading game along with number of rides which were not loaded.
+async fn insert_game(
+    payload: &GameInfo,
+    ids: &GameIds,
+    transaction: &mut PgConnection,
+) -> Result<(Insertion, usize), sqlx::Error> {
+    let mut savepoint = transaction.begin().await?;
+
+    let Some((game_id, inserted)) = upsert_game(
+        payload,
+        ids.teams.0,
+        ids.teams.1,
+        ids.stadium,
+        ids.league,
+        &mut savepoint,
+    )
+    .await?
+    else {
+        return Ok((Insertion::Unchanged, 0));
+    };
+
+    // Game loaded earlier is replaced as a whole, together with its runs and scores.
+    if !inserted {
+        clear_game(game_id, &mut savepoint).await?;
+    }
 
-    main_hash_map.extend(id2.into_iter());
+    insert_lineups(payload, ids.teams, &ids.players, game_id, &mut savepoint).await?;
+    let missing_rides = insert_runs(payload, &ids.players, game_id, &mut savepoint).await?;
 
-    let mut transaction = db.begin().await?;
+    savepoint.commit().await?;
 
-    let game_id = insert_game(&payload, team_1_id, team_2_id, stadium, &mut transaction).await?;
-    println!("GAME_ID: {game_id}");
+    let insertion = if inserted {
+        Insertion::Inserted
+    } else {
+        Insertion::Updated
+    };
 
-    let mut index = 0;
-    for player in payload.team_one().players().iter().filter(|player| !player.name().eq_ignore_ascii_case("brak") || !player.surname().eq_ignore_ascii_case("zawodnika")) {
-        let key = (player.name(), player.surname());
-        let id = main_hash_map.get(&key).unwrap();
-        
-        insert_player_game_team(&mut transaction, *id, team_1_id, game_id).await?;
+    Ok((insertion, missing_rides))
+}
 
-        for score in player.scores().iter() {
-            insert_player_score(*id, game_id, index as i32, score, &mut transaction).await?;
-            index += 1;
-        }
-    }
+/// Inserts fixture, or updates fixture with the same site if it was not played yet.
+pub async fn upsert_fixture(
+    fixture: &Fixture,
+    cache: &IdCache,
+    db: &PgPool,
+) -> Result<Insertion, sqlx::Error> {
+    let team_1_id = cached(
+        &cache.teams,
+        fixture.team_one(),
+        check_team(fixture.team_one(), db),
+    )
+    .await?;
+    let team_2_id = cached(
+        &cache.teams,
+        fixture.team_two(),
+        check_team(fixture.team_two(), db),
+    )
+    .await?;
+    let league = cached(&cache.leagues, fixture.league(), check_league(fixture.league(), db)).await?;
+
+    let record = sqlx::query_file!(
+        "queries/upsert_fixture.sql",
+        team_1_id,
+        team_2_id,
+        fixture.date().assume_utc(),
+        league,
+        fixture.status().as_str(),
+        fixture.source_url()
+    )
+    .fetch_optional(db)
+    .await?;
 
-    for player in payload.team_two().players().iter().filter(|player| !player.name().eq_ignore_ascii_case("brak") || !player.surname().eq_ignore_ascii_case("zawodnika")) {
-        let key = (player.name(), player.surname());
-        let id = main_hash_map.get(&key).unwrap();
-        
-        insert_player_game_team(&mut transaction, *id, team_2_id, game_id).await?;
+    Ok(match record {
+        None => Insertion::Unchanged,
+        Some(record) if record.inserted => Insertion::Inserted,
+        Some(_) => Insertion::Updated,
+    })
+}
 
-        for score in player.scores().iter() {
-            insert_player_score(*id, game_id, index as i32, score, &mut transaction).await?;
-            index += 1;
-        }
+/// Inserts games in a single transaction. Every game has its own savepoint,
+/// so a game which cannot be inserted does not stop the others.
+/// Returns outcome of every game along with number of its rides which were not loaded.
+pub async fn insert_into_database(
+    db: Arc<PgPool>,
+    cache: Arc<IdCache>,
+    games: Vec<GameInfo>,
+) -> Result<Vec<Result<(Insertion, usize), sqlx::Error>>, sqlx::Error> {
+    // Riders are resolved before the transaction starts, because linking a rider to his
+    // profile site waits for transactions which refer to him.
+    let mut resolved = Vec::with_capacity(games.len());
+    for game in &games {
+        resolved.push(resolve_ids(game, &cache, &db).await);
     }
 
-    for run in payload.runs() {
-        let (time_int, time_dec) = run.time();
-        let run_id = create_run(
-            run.position() as i32,
-            time_int,
-            time_dec,
-            game_id,
-            &mut transaction,
-        )
-        .await?;
+    let mut transaction = db.begin().await?;
+    let mut outcomes = Vec::with_capacity(games.len());
 
-        for score in run.player_scores() {
-            let player_id = main_hash_map.get(&score.name());
-
-            if let Some(player_id) = player_id {
-                insert_run_squad_score(
-                    run_id,
-                    *player_id,
-                    score.score(),
-                    &mut transaction,
-                )
-                .await?;
-            }
-        }
+    for (game, ids) in games.iter().zip(resolved) {
+        let outcome = match ids {
+            Ok(ids) => insert_game(game, &ids, &mut transaction).await,
+            Err(e) => Err(e),
+        };
+
+        outcomes.push(outcome);
     }
 
     transaction.commit().await?;
 
-    Ok(())
+    Ok(outcomes)
+}
+
+#[cfg(test)]
+mod tests {
+    use speedwayrs_types::{IncidentKind, PlayerResult};
+    use sqlx::{Connection, PgConnection};
+
+    /// Inserts longest serialized incident into copies of result columns.
+    /// Runs only when `DATABASE_URL` points to migrated database.
+    #[tokio::test]
+    async fn incident_fits_result_columns() {
+        let Ok(url) = std::env::var("DATABASE_URL") else {
+            return;
+        };
+        let mut db = PgConnection::connect(&url).await.unwrap();
+        let result = PlayerResult::Incident {
+            kind: IncidentKind::ExcludedForCausingFall,
+            symbol: "w/2min".into(),
+        }
+        .to_string();
+        assert!(result.len() > 30);
+
+        sqlx::query("CREATE TEMPORARY TABLE squad_result (LIKE application.squad)")
+            .execute(&mut db)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO squad_result (id, run, player_id, result) VALUES (1, 1, 1, $1)")
+            .bind(&result)
+            .execute(&mut db)
+            .await
+            .unwrap();
+
+        sqlx::query("CREATE TEMPORARY TABLE player_score_result (LIKE application.player_score)")
+            .execute(&mut db)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO player_score_result (game_id, player_id, round, score) VALUES (1, 1, 0, $1)")
+            .bind(&result)
+            .execute(&mut db)
+            .await
+            .unwrap();
+    }
 }
@@ -0,0 +1,4 @@
+pub mod admin;
+pub mod insertion;
+pub mod pipeline;
+pub mod validation;
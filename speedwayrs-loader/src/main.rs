@@ -1,130 +1,247 @@
-mod insertion;
-mod scraper_types;
+mod config;
 
-use scraper_types::GameInfo;
+use clap::Parser;
+use config::{AdminCommand, ProgramConfig};
+use speedwayrs_loader::{admin, pipeline::Pipeline, validation};
+use speedwayrs_types::scraper_types::GameInfo;
 use sqlx::PgPool;
-use std::sync::Arc;
-use std::{fmt::Debug, fs::File};
-use tokio::sync::mpsc::{self, UnboundedReceiver};
-
-enum LoaderTask {
-    Load(GameInfo),
-    End,
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+    path::Path,
+    process::ExitCode,
+};
+
+/// Checks whether the first non-whitespace character of input opens a JSON array.
+/// Skipped whitespace is consumed from the reader.
+fn is_json_array<R: BufRead>(reader: &mut R) -> std::io::Result<bool> {
+    loop {
+        let buffer = reader.fill_buf()?;
+
+        let Some(&first) = buffer.first() else {
+            return Ok(false);
+        };
+
+        if first.is_ascii_whitespace() {
+            reader.consume(1);
+        } else {
+            return Ok(first == b'[');
+        }
+    }
 }
 
-impl Debug for LoaderTask {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Loader")
+/// Opens scraper's file, or standard input if path is `-`.
+fn open_input(path: &Path) -> Result<Box<dyn BufRead>, String> {
+    if path == Path::new("-") {
+        return Ok(Box::new(std::io::stdin().lock()));
     }
+
+    let file = File::open(path).map_err(|e| {
+        format!(
+            "Unable to open scraper's file {}. Error = [{e:?}]",
+            path.display()
+        )
+    })?;
+
+    Ok(Box::new(BufReader::new(file)))
 }
 
-async fn loader(database: Arc<PgPool>, mut rx: UnboundedReceiver<LoaderTask>) {
-    let mut task_set = tokio::task::JoinSet::new();
+/// Deserializes every game from scraper's file and passes it to `handle`.
+/// Returns number of read games. Games read before an error are passed as well.
+fn read_games(path: &Path, mut handle: impl FnMut(GameInfo)) -> Result<usize, String> {
+    let mut reader = open_input(path)?;
+
+    let mut read = 0;
+    let mut send = |input: GameInfo| {
+        read += 1;
+
+        handle(input);
+    };
 
-    while let Some(msg) = rx.recv().await {
-        match msg {
-            LoaderTask::Load(payload) => {
-                let database = database.clone();
+    if is_json_array(&mut reader)
+        .map_err(|e| format!("Unable to read scraper's file. Error = [{e:?}]"))?
+    {
+        // Array written by scraper with `--format array`.
+        let games: Vec<GameInfo> = serde_json::from_reader(reader)
+            .map_err(|e| format!("Error while deserializing. Error = [{e:?}]"))?;
 
-                task_set.spawn(async move {
-                    let result = insertion::insert_into_database(database, payload).await;
+        games.into_iter().for_each(&mut send);
+    } else {
+        // JSON Lines, or games simply concatenated by older scraper versions.
+        let deserializer = serde_json::Deserializer::from_reader(reader).into_iter::<GameInfo>();
 
-                    if let Err(e) = result {
-                        eprintln!("Error while inserting. Error = [{e:?}]");
+        for input in deserializer {
+            match input {
+                Err(e) => {
+                    if e.is_eof() {
+                        break;
+                    } else {
+                        return Err(format!("Error while deserializing. Error = [{e:?}]"));
                     }
-                });
-                // tokio::time::sleep(std::time::Duration::from_secs_f64(0.5)).await;
-            }
-            LoaderTask::End => {
-                break;
+                }
+                Ok(input) => send(input),
             }
         }
     }
 
-    while let Some(outcome) = task_set.join_next().await {
-        if let Err(e) = outcome {
-            eprintln!("Join error on tokio. Error = [{e:?}]");
+    Ok(read)
+}
+
+/// Checks consistency of every game in scraper's files without connecting to database.
+fn check_files(files: &[impl AsRef<Path>]) -> ExitCode {
+    let mut read = 0;
+    let mut invalid = 0;
+    let mut unreadable = 0;
+
+    for path in files {
+        let result = read_games(path.as_ref(), |game| {
+            let problems = validation::check_game(&game);
+
+            if problems.is_empty() {
+                return;
+            }
+
+            invalid += 1;
+
+            println!(
+                "{} - {} ({}):",
+                game.team_one().name(),
+                game.team_two().name(),
+                game.date()
+            );
+
+            for problem in problems {
+                println!("  {problem}");
+            }
+        });
+
+        match result {
+            Ok(count) => read += count,
+            Err(e) => {
+                unreadable += 1;
+
+                eprintln!("{e}");
+            }
         }
     }
+
+    println!("Checked {read} games, {invalid} of them are invalid.");
+
+    if invalid > 0 || unreadable > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
 }
 
-fn main() -> Result<(), String> {
-    dotenvy::dotenv().unwrap();
+/// Runs admin operation on riders.
+async fn run_admin(command: &AdminCommand, pool: &PgPool) -> Result<(), String> {
+    match command {
+        AdminCommand::Merge { kept, removed } => {
+            admin::merge_players(pool, *kept, *removed)
+                .await
+                .map_err(|e| format!("Unable to merge players. Error = [{e:?}]"))?;
+
+            println!("Player {removed} was merged into player {kept}.");
+        }
+        AdminCommand::Split {
+            player,
+            source_url,
+            games,
+        } => {
+            let created = admin::split_player(pool, *player, games, source_url)
+                .await
+                .map_err(|e| format!("Unable to split player. Error = [{e:?}]"))?;
+
+            println!("Rides of player {player} were moved to new player {created}.");
+        }
+    }
+
+    Ok(())
+}
 
-    // Iterator skipping program executable path.
-    let mut args = std::env::args().skip(1);
-    let database_str =
-        std::env::var("LOADER_POSTGRES").expect("Unable to load LOADER_POSTGRES env variable.");
+/// Loads games from every file. Returns number of files which could not be read.
+fn load_files(files: &[impl AsRef<Path>], pipeline: &Pipeline) -> usize {
+    let mut read = 0;
+    let mut unreadable = 0;
 
-    let path = match args.next() {
-        None => {
-            println!("ERROR: First argument should be path to scraper's data!");
+    for path in files {
+        match read_games(path.as_ref(), |input| pipeline.send(input)) {
+            Ok(count) => read += count,
+            Err(e) => {
+                unreadable += 1;
 
-            return Err("Path to scraper's file not found.".into());
+                eprintln!("{e}");
+            }
         }
-        Some(path) => path,
-    };
+    }
 
-    let (tx, rx) = mpsc::unbounded_channel();
+    eprintln!("Deserializer read {read} structs.");
 
-    let tokio_handle = std::thread::spawn(move || {
-        let loader_runtime = tokio::runtime::Builder::new_current_thread()
-            .worker_threads(3)
-            .enable_all()
-            .build()
-            .expect("Unable to build tokio runtime.");
+    unreadable
+}
 
-        loader_runtime.block_on(async move {
-            let postgres_pool = match sqlx::postgres::PgPool::connect(&database_str).await {
-                Err(e) => {
-                    eprintln!("Error returned from database. Error = {e:?}");
+fn main() -> ExitCode {
+    // Connection string can be kept in `.env` file, but it is not required.
+    dotenvy::dotenv().ok();
 
-                    std::process::exit(1);
-                }
-                Ok(pool) => pool,
-            };
+    let config = ProgramConfig::parse();
 
-            let sync_pool = Arc::new(postgres_pool);
+    if config.check() {
+        return check_files(&config.files());
+    }
 
-            loader(sync_pool, rx).await;
-        });
-    });
+    let Some(database_url) = config.database_url() else {
+        eprintln!("Database url should be given with --database-url or LOADER_POSTGRES.");
 
-    let file =
-        File::open(path).map_err(|e| format!("Unable to open scraper's file. Error = [{e:?}]"))?;
+        return ExitCode::FAILURE;
+    };
 
-    let deserializer = serde_json::Deserializer::from_reader(&file).into_iter::<GameInfo>();
+    if let Some(command) = config.command() {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("Unable to build tokio runtime.");
 
-    let mut read = 0;
-    for input in deserializer {
-        match input {
+        let result = runtime.block_on(async move {
+            let pool = PgPool::connect(database_url)
+                .await
+                .map_err(|e| format!("Error returned from database. Error = [{e:?}]"))?;
+
+            run_admin(command, &pool).await
+        });
+
+        return match result {
+            Ok(()) => ExitCode::SUCCESS,
             Err(e) => {
-                if e.is_eof() {
-                    break;
-                } else {
-                    eprintln!("Error while deserializing. Error = [{e:?}]");
+                eprintln!("{e}");
 
-                    return Err("Deserializing error.".into());
-                }
+                ExitCode::FAILURE
             }
-            Ok(input) => {
-                read += 1;
+        };
+    }
 
-                if let Err(e) = tx.send(LoaderTask::Load(input)) {
-                    panic!("Error while sending task. Error = [{e:?}]");
-                }
-            }
+    let pipeline = match Pipeline::connect(database_url) {
+        Err(e) => {
+            eprintln!("Error returned from database. Error = [{e:?}]");
+
+            return ExitCode::FAILURE;
         }
-    }
+        Ok(pipeline) => pipeline,
+    };
 
-    if let Err(e) = tx.send(LoaderTask::End) {
-        panic!(
-            "Error while sending end signal. Error = [{}]",
-            e.to_string()
-        );
-    }
+    let unreadable = load_files(&config.files(), &pipeline);
+    let (summary, _) = pipeline.finish();
 
-    eprintln!("Deserializer read {read} structs.");
+    println!("{summary}");
+
+    if unreadable > 0 {
+        println!("{unreadable} files could not be read.");
+    }
 
-    tokio_handle.join().map_err(|e| format!("{e:?}"))
+    if summary.failed > 0 || unreadable > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
 }
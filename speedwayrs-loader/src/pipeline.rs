@@ -0,0 +1,340 @@
+use std::{
+    fmt::{Debug, Display},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use speedwayrs_types::scraper_types::{Fixture, GameInfo};
+use sqlx::{postgres::PgPoolOptions, PgPool};
+use tokio::{
+    runtime::Runtime,
+    sync::{
+        mpsc::{self, Receiver, Sender},
+        Semaphore,
+    },
+    task::JoinHandle,
+};
+
+use crate::insertion::{self, Insertion};
+
+enum LoaderTask {
+    Load(Box<GameInfo>),
+    Fixture(Box<Fixture>),
+    End,
+}
+
+impl Debug for LoaderTask {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Loader")
+    }
+}
+
+/// Number of batches inserted at the same time.
+const CONCURRENT_BATCHES: usize = 4;
+/// Maximal number of games inserted in a single transaction.
+const BATCH_SIZE: usize = 50;
+/// Number of deserialized games waiting for insertion.
+const QUEUE_SIZE: usize = CONCURRENT_BATCHES * BATCH_SIZE;
+/// Progress is printed every time this many games are loaded.
+const PROGRESS_STEP: usize = 500;
+
+#[derive(Default)]
+struct Progress {
+    /// Games and fixtures sent to the pipeline.
+    queued: AtomicUsize,
+    inserted: AtomicUsize,
+    updated: AtomicUsize,
+    skipped: AtomicUsize,
+    failed: AtomicUsize,
+    missing_rides: AtomicUsize,
+    /// Sites of games which are in database, not yet taken by the pipeline owner.
+    loaded: Mutex<Vec<String>>,
+}
+
+impl Progress {
+    fn record(&self, outcome: Result<Insertion, sqlx::Error>) {
+        let counter = match outcome {
+            Ok(Insertion::Inserted) => &self.inserted,
+            Ok(Insertion::Updated) => &self.updated,
+            Ok(Insertion::Unchanged) => &self.skipped,
+            Err(e) => {
+                eprintln!("Error while inserting. Error = [{e:?}]");
+
+                &self.failed
+            }
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+
+        if self.processed().is_multiple_of(PROGRESS_STEP) {
+            eprintln!("Processed {} games.", self.processed());
+        }
+    }
+
+    fn processed(&self) -> usize {
+        [&self.inserted, &self.updated, &self.skipped, &self.failed]
+            .iter()
+            .map(|counter| counter.load(Ordering::Relaxed))
+            .sum()
+    }
+
+    /// Counts queued games without outcome as failed, as their task was aborted.
+    fn fail_unprocessed(&self) {
+        let unprocessed = self.queued.load(Ordering::Relaxed).saturating_sub(self.processed());
+
+        if unprocessed > 0 {
+            eprintln!("{unprocessed} games were not loaded, as their task was aborted.");
+            self.failed.fetch_add(unprocessed, Ordering::Relaxed);
+        }
+    }
+}
+
+#[derive(Default)]
+struct Batch {
+    games: Vec<GameInfo>,
+    fixtures: Vec<Fixture>,
+}
+
+impl Batch {
+    fn push(&mut self, task: LoaderTask) {
+        match task {
+            LoaderTask::Load(payload) => self.games.push(*payload),
+            LoaderTask::Fixture(fixture) => self.fixtures.push(*fixture),
+            LoaderTask::End => {}
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.games.len() + self.fixtures.len()
+    }
+}
+
+/// Waits for the next game and takes games which are already queued behind it.
+async fn next_batch(rx: &mut Receiver<LoaderTask>) -> Option<Batch> {
+    let first = match rx.recv().await {
+        None | Some(LoaderTask::End) => return None,
+        Some(task) => task,
+    };
+
+    let mut batch = Batch::default();
+    batch.push(first);
+
+    while batch.len() < BATCH_SIZE {
+        match rx.try_recv() {
+            // Closed channel makes the next call return `None`.
+            Ok(LoaderTask::End) => {
+                rx.close();
+                break;
+            }
+            Ok(task) => batch.push(task),
+            Err(_) => break,
+        }
+    }
+
+    Some(batch)
+}
+
+async fn loader(database: Arc<PgPool>, mut rx: Receiver<LoaderTask>, progress: Arc<Progress>) {
+    let mut task_set = tokio::task::JoinSet::new();
+
+    let cache = Arc::new(insertion::IdCache::default());
+    let permits = Arc::new(Semaphore::new(CONCURRENT_BATCHES));
+
+    while let Some(batch) = next_batch(&mut rx).await {
+        let permit = permits.clone().acquire_owned().await.unwrap();
+        let database = database.clone();
+        let cache = cache.clone();
+        let progress = progress.clone();
+
+        task_set.spawn(async move {
+            // Fixtures are independent of each other, so they do not need a transaction.
+            for fixture in &batch.fixtures {
+                progress.record(insertion::upsert_fixture(fixture, &cache, &database).await);
+            }
+
+            if batch.games.is_empty() {
+                return;
+            }
+
+            let size = batch.games.len();
+            let urls = batch
+                .games
+                .iter()
+                .map(|game| game.source_url().map(String::from))
+                .collect::<Vec<_>>();
+            let result = insertion::insert_into_database(database, cache, batch.games).await;
+            drop(permit);
+
+            match result {
+                Ok(outcomes) => outcomes.into_iter().zip(urls).for_each(|(outcome, url)| {
+                    let outcome = outcome.map(|(insertion, missing_rides)| {
+                        progress.missing_rides.fetch_add(missing_rides, Ordering::Relaxed);
+                        progress.loaded.lock().unwrap().extend(url);
+
+                        insertion
+                    });
+
+                    progress.record(outcome)
+                }),
+                Err(e) => {
+                    progress.failed.fetch_add(size, Ordering::Relaxed);
+
+                    eprintln!("Error while inserting batch of {size} games. Error = [{e:?}]");
+                }
+            }
+        });
+    }
+
+    while let Some(outcome) = task_set.join_next().await {
+        if let Err(e) = outcome {
+            eprintln!("Join error on tokio. Error = [{e:?}]");
+        }
+    }
+}
+
+/// Counts of games processed by pipeline.
+#[derive(Debug, Clone, Copy)]
+pub struct Summary {
+    pub inserted: usize,
+    pub updated: usize,
+    pub skipped: usize,
+    pub failed: usize,
+    /// Rides of riders who are not in any lineup. Games are loaded without them.
+    pub missing_rides: usize,
+    pub elapsed: Duration,
+}
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Inserted {} games, updated {}, skipped {} unchanged, {} failed, in {:.2?}.",
+            self.inserted, self.updated, self.skipped, self.failed, self.elapsed
+        )?;
+
+        if self.missing_rides > 0 {
+            write!(
+                f,
+                " {} rides of riders who are not in any lineup were not loaded.",
+                self.missing_rides
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Inserts games into database in the background, while they are still read or scraped.
+pub struct Pipeline {
+    runtime: Runtime,
+    tx: Sender<LoaderTask>,
+    loader: JoinHandle<()>,
+    progress: Arc<Progress>,
+    start: Instant,
+}
+
+impl Pipeline {
+    /// Connects to database and starts inserting tasks.
+    pub fn connect(database_url: &str) -> Result<Self, sqlx::Error> {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(4)
+            .enable_all()
+            .build()
+            .expect("Unable to build tokio runtime.");
+
+        let pool = runtime.block_on(
+            PgPoolOptions::new()
+                // Every batch holds its transaction and looks up riders on another connection.
+                .max_connections(2 * CONCURRENT_BATCHES as u32)
+                .connect(database_url),
+        )?;
+
+        let (tx, rx) = mpsc::channel(QUEUE_SIZE);
+        let progress = Arc::new(Progress::default());
+
+        let loader = runtime.spawn(loader(Arc::new(pool), rx, progress.clone()));
+
+        Ok(Self {
+            runtime,
+            tx,
+            loader,
+            progress,
+            start: Instant::now(),
+        })
+    }
+
+    /// Queues game for insertion. Blocks while too many games are waiting.
+    /// Should not be called from asynchronous code.
+    pub fn send(&self, game: GameInfo) {
+        self.progress.queued.fetch_add(1, Ordering::Relaxed);
+
+        if let Err(e) = self.tx.blocking_send(LoaderTask::Load(Box::new(game))) {
+            panic!("Error while sending task. Error = [{e:?}]");
+        }
+    }
+
+    /// Queues fixture for insertion. Blocks while too many games are waiting.
+    /// Should not be called from asynchronous code.
+    pub fn send_fixture(&self, fixture: Fixture) {
+        self.progress.queued.fetch_add(1, Ordering::Relaxed);
+
+        if let Err(e) = self.tx.blocking_send(LoaderTask::Fixture(Box::new(fixture))) {
+            panic!("Error while sending task. Error = [{e:?}]");
+        }
+    }
+
+    /// Takes sites of games which were loaded into database since the previous call.
+    pub fn take_loaded(&self) -> Vec<String> {
+        std::mem::take(&mut *self.progress.loaded.lock().unwrap())
+    }
+
+    /// Returns counts of games processed so far.
+    pub fn summary(&self) -> Summary {
+        Summary {
+            inserted: self.progress.inserted.load(Ordering::Relaxed),
+            updated: self.progress.updated.load(Ordering::Relaxed),
+            skipped: self.progress.skipped.load(Ordering::Relaxed),
+            failed: self.progress.failed.load(Ordering::Relaxed),
+            missing_rides: self.progress.missing_rides.load(Ordering::Relaxed),
+            elapsed: self.start.elapsed(),
+        }
+    }
+
+    /// Waits until every queued game is inserted.
+    /// Returns summary along with sites of games loaded since the last `take_loaded` call.
+    pub fn finish(mut self) -> (Summary, Vec<String>) {
+        if let Err(e) = self.tx.blocking_send(LoaderTask::End) {
+            panic!("Error while sending end signal. Error = [{e:?}]");
+        }
+
+        if let Err(e) = self.runtime.block_on(&mut self.loader) {
+            eprintln!("Join error on tokio. Error = [{e:?}]");
+        }
+        self.progress.fail_unprocessed();
+
+        (self.summary(), self.take_loaded())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::Ordering;
+
+    use super::Progress;
+    use crate::insertion::Insertion;
+
+    #[test]
+    fn failing_games_of_aborted_tasks() {
+        let progress = Progress::default();
+        progress.queued.store(5, Ordering::Relaxed);
+        progress.record(Ok(Insertion::Inserted));
+        progress.record(Ok(Insertion::Unchanged));
+
+        progress.fail_unprocessed();
+
+        assert_eq!(progress.failed.load(Ordering::Relaxed), 3);
+        assert_eq!(progress.processed(), 5);
+    }
+}
@@ -0,0 +1,262 @@
+use std::collections::{HashMap, HashSet};
+
+use speedwayrs_types::{PlayerResult, SubstitutionKind};
+
+use speedwayrs_types::scraper_types::{Gap, GameInfo, Team};
+
+const RUNS_IN_GAME: u8 = 15;
+const RIDERS_IN_RUN: usize = 4;
+
+fn points(result: &PlayerResult) -> Option<u8> {
+    match result {
+        PlayerResult::Score(points) | PlayerResult::ScoreWithStar(points) => Some(*points),
+        _ => None,
+    }
+}
+
+fn lineup(team: &Team) -> HashSet<String> {
+    team.players()
+        .iter()
+        .map(|player| player.full_name())
+        .collect()
+}
+
+/// Returns description of every inconsistency found in game.
+/// Empty list means that game can be loaded.
+pub fn check_game(game: &GameInfo) -> Vec<String> {
+    let mut problems = Vec::new();
+
+    let team_one = lineup(game.team_one());
+    let team_two = lineup(game.team_two());
+
+    // Riders from lineup rows which were not parsed cannot be found in any lineup.
+    let missing_lineup = game
+        .gaps()
+        .iter()
+        .any(|gap| matches!(gap, Gap::LineupRow { .. }));
+
+    let mut run_numbers = HashSet::new();
+    let mut team_points = (0u32, 0u32);
+
+    for run in game.runs() {
+        let number = run.number();
+
+        if !(1..=RUNS_IN_GAME).contains(&number) {
+            problems.push(format!("Run number {number} is outside of 1..{RUNS_IN_GAME}."));
+        }
+
+        if !run_numbers.insert(number) {
+            problems.push(format!("Run {number} is listed more than once."));
+        }
+
+        let scores = run.player_scores();
+
+        if scores.len() != RIDERS_IN_RUN {
+            problems.push(format!(
+                "Run {number} has {} riders instead of {RIDERS_IN_RUN}.",
+                scores.len()
+            ));
+        }
+
+        let mut places = HashMap::new();
+
+        for score in scores {
+            let name = score.name();
+            let points = points(score.score());
+
+            match (team_one.contains(name), team_two.contains(name)) {
+                (true, _) => team_points.0 += u32::from(points.unwrap_or(0)),
+                (_, true) => team_points.1 += u32::from(points.unwrap_or(0)),
+                _ if missing_lineup => {}
+                _ => problems.push(format!("Rider {name} from run {number} is not in any lineup.")),
+            }
+
+            let Some(points) = points else {
+                continue;
+            };
+
+            // Joker scores double points.
+            let is_joker = score
+                .substitution()
+                .is_some_and(|substitution| substitution.kind() == SubstitutionKind::Joker);
+            let place_points = if is_joker { points / 2 } else { points };
+
+            if place_points > 3 {
+                problems.push(format!("Rider {name} scored {points} points in run {number}."));
+            } else if let Some(other) = places.insert(place_points, name) {
+                problems.push(format!(
+                    "Riders {other} and {name} scored the same points in run {number}."
+                ));
+            }
+        }
+
+        // Riders who finished get points from 3 downwards, so the lowest points show
+        // how many of them did.
+        let finished = places.len() as u8;
+        if places.keys().any(|&points| points + finished < 4) {
+            problems.push(format!("Points in run {number} are not 3-2-1-0."));
+        }
+    }
+
+    // Points of runs or riders which were not parsed are missing from sums.
+    if !game.gaps().is_empty() {
+        return problems;
+    }
+
+    for (team, points) in [(game.team_one(), team_points.0), (game.team_two(), team_points.1)] {
+        if u32::from(team.score()) != points {
+            problems.push(format!(
+                "Team {} has {} points, but its riders scored {points} points in runs.",
+                team.name(),
+                team.score()
+            ));
+        }
+    }
+
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use speedwayrs_types::{
+        scraper_types::{Gap, GameInfo, Player, PlayerRunScore, Run, Substitution, Team},
+        IncidentKind, MatchDetails, MatchStatus, PlayerResult, SubstitutionKind,
+    };
+    use time::macros::datetime;
+
+    use super::check_game;
+
+    fn team(name: &str, surnames: &[&str], points: u16) -> Team {
+        let players = surnames
+            .iter()
+            .enumerate()
+            .map(|(number, surname)| {
+                Player::new("Jan".into(), surname.to_string(), number as u32, None, Vec::new())
+            })
+            .collect();
+
+        Team::new(name.into(), points, players)
+    }
+
+    fn ride(surname: &str, score: PlayerResult) -> PlayerRunScore {
+        PlayerRunScore::new(format!("Jan {surname}"), score, None, None)
+    }
+
+    /// Game of riders `A`, `B` against `C`, `D` with given team scores.
+    fn game(runs: Vec<Run>, points: (u16, u16), gaps: Vec<Gap>) -> GameInfo {
+        GameInfo::new(
+            "pge-ekstraliga".into(),
+            None,
+            team("Unia", &["A", "B"], points.0),
+            team("Sparta", &["C", "D"], points.1),
+            "Stadion".into(),
+            datetime!(2022-06-12 19:15),
+            MatchStatus::Finished,
+            MatchDetails::default(),
+            runs,
+            gaps,
+            Vec::new(),
+        )
+    }
+
+    fn run(number: u8, scores: [(&str, PlayerResult); 4]) -> Run {
+        Run::new(
+            number,
+            None,
+            scores
+                .into_iter()
+                .map(|(surname, score)| ride(surname, score))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn valid_heat() {
+        use PlayerResult::{Score, ScoreWithStar};
+
+        let runs = vec![run(
+            1,
+            [("A", Score(3)), ("C", Score(2)), ("B", ScoreWithStar(1)), ("D", Score(0))],
+        )];
+
+        assert!(check_game(&game(runs, (4, 2), Vec::new())).is_empty());
+    }
+
+    #[test]
+    fn heat_with_fall() {
+        use PlayerResult::Score;
+
+        let fall = PlayerResult::Incident {
+            kind: IncidentKind::Fall,
+            symbol: "u".into(),
+        };
+        let runs = vec![run(1, [("A", Score(3)), ("C", Score(2)), ("B", Score(1)), ("D", fall)])];
+
+        assert!(check_game(&game(runs, (4, 2), Vec::new())).is_empty());
+    }
+
+    #[test]
+    fn duplicated_place() {
+        use PlayerResult::Score;
+
+        let runs = vec![run(1, [("A", Score(3)), ("C", Score(3)), ("B", Score(1)), ("D", Score(0))])];
+
+        assert_eq!(
+            check_game(&game(runs, (4, 3), Vec::new())),
+            vec![
+                "Riders Jan A and Jan C scored the same points in run 1.",
+                "Points in run 1 are not 3-2-1-0.",
+            ]
+        );
+    }
+
+    #[test]
+    fn joker_with_double_points() {
+        use PlayerResult::Score;
+
+        let joker = PlayerRunScore::new(
+            "Jan A".into(),
+            Score(6),
+            None,
+            Some(Substitution::new(SubstitutionKind::Joker, None)),
+        );
+        let runs = vec![Run::new(
+            1,
+            None,
+            vec![joker, ride("C", Score(2)), ride("B", Score(1)), ride("D", Score(0))],
+        )];
+
+        assert!(check_game(&game(runs, (7, 2), Vec::new())).is_empty());
+    }
+
+    #[test]
+    fn rider_outside_of_lineups() {
+        use PlayerResult::Score;
+
+        let runs = vec![run(1, [("A", Score(3)), ("C", Score(2)), ("E", Score(1)), ("D", Score(0))])];
+
+        assert_eq!(
+            check_game(&game(runs, (3, 2), Vec::new())),
+            vec!["Rider Jan E from run 1 is not in any lineup."]
+        );
+    }
+
+    #[test]
+    fn wrong_team_score() {
+        use PlayerResult::Score;
+
+        let runs = || vec![run(1, [("A", Score(3)), ("C", Score(2)), ("B", Score(1)), ("D", Score(0))])];
+
+        assert_eq!(
+            check_game(&game(runs(), (5, 2), Vec::new())),
+            vec!["Team Unia has 5 points, but its riders scored 4 points in runs."]
+        );
+
+        // Sums of games with parts which were not parsed are not compared.
+        let gaps = vec![Gap::Run {
+            number: 2,
+            reason: "".into(),
+        }];
+        assert!(check_game(&game(runs(), (5, 2), gaps)).is_empty());
+    }
+}
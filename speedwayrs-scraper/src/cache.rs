@@ -0,0 +1,130 @@
+use std::{fs, path::PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+use once_cell::sync::OnceCell;
+use sha2::{Digest, Sha256};
+
+static CACHE: OnceCell<ResponseCache> = OnceCell::new();
+
+// Longest readable part of file name, which keeps names below limits of file systems.
+const MAX_PREFIX_LENGTH: usize = 100;
+
+/// On-disk storage of downloaded sites keyed by their url.
+/// In offline mode every request is served from the cache and nothing is sent over network.
+pub struct ResponseCache {
+    folder: PathBuf,
+    offline: bool,
+}
+
+impl ResponseCache {
+    /// Installs cache used by every HTTP request made by the program.
+    pub fn install(folder: PathBuf, offline: bool) -> Result<()> {
+        let set_result = CACHE.set(Self { folder, offline });
+
+        if set_result.is_err() {
+            Err(anyhow!("ResponseCache was already initialized!"))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Returns installed cache, if any.
+    pub fn installed() -> Option<&'static Self> {
+        CACHE.get()
+    }
+
+    /// Returns true if requests should not leave the cache.
+    pub fn offline(&self) -> bool {
+        self.offline
+    }
+
+    /// Returns cached body of site, or `None` if site was never stored.
+    pub fn load(&self, url: &str) -> Result<Option<String>> {
+        let path = self.path(url);
+
+        if !path.is_file() {
+            return Ok(None);
+        }
+
+        let body = fs::read_to_string(&path)
+            .with_context(|| format!("Unable to read cached site {}.", path.display()))?;
+
+        Ok(Some(body))
+    }
+
+    /// Stores body of site, replacing previous version.
+    pub fn store(&self, url: &str, body: &str) -> Result<()> {
+        let path = self.path(url);
+
+        fs::write(&path, body)
+            .with_context(|| format!("Unable to write cached site {}.", path.display()))
+    }
+
+    fn path(&self, url: &str) -> PathBuf {
+        self.folder.join(file_name(url))
+    }
+}
+
+/// Converts url into a file name made of its readable prefix and hash of the whole url, e.g.
+/// `https://a.pl/zuzel/relacja/1` becomes `a.pl_zuzel_relacja_1-<hash>.html`. Urls which
+/// differ only in characters replaced in the prefix still get different names.
+fn file_name(url: &str) -> String {
+    let stripped = url
+        .trim_start_matches("https://")
+        .trim_start_matches("http://")
+        .trim_end_matches('/');
+
+    let prefix: String = stripped
+        .chars()
+        .take(MAX_PREFIX_LENGTH)
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '.' || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+
+    let hash = Sha256::digest(url.as_bytes());
+    let hash: String = hash[..16].iter().map(|byte| format!("{byte:02x}")).collect();
+
+    format!("{prefix}-{hash}.html")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::file_name;
+
+    #[test]
+    fn url_to_file_name() {
+        let name = file_name("https://sportowefakty.wp.pl/zuzel/pge-ekstraliga/terminarz");
+
+        assert!(name.starts_with("sportowefakty.wp.pl_zuzel_pge-ekstraliga_terminarz-"));
+        assert!(name.ends_with(".html"));
+        assert_eq!(
+            name,
+            file_name("https://sportowefakty.wp.pl/zuzel/pge-ekstraliga/terminarz")
+        );
+    }
+
+    #[test]
+    fn distinct_urls_get_distinct_names() {
+        let names = [
+            file_name("https://a.pl/zuzel/relacja/1?x=2"),
+            file_name("https://a.pl/zuzel/relacja/1_x=2"),
+            file_name("https://a.pl/zuzel/relacja/1/x/2"),
+        ];
+
+        assert_ne!(names[0], names[1]);
+        assert_ne!(names[0], names[2]);
+        assert_ne!(names[1], names[2]);
+    }
+
+    #[test]
+    fn long_url_is_shortened() {
+        let url = format!("https://a.pl/{}", "x".repeat(1000));
+
+        assert!(file_name(&url).len() < 255);
+    }
+}
@@ -0,0 +1,252 @@
+use std::sync::Arc;
+
+use anyhow::{anyhow, Context, Result};
+use scraper::{ElementRef, Html, Selector};
+use speedwayrs_types::MatchStatus;
+
+use crate::{
+    config::ProgramConfig,
+    http::HttpRequester,
+    league::League,
+    source::{PageKind, SelectorCheck, Source},
+};
+
+/// Length of element text shown in the report.
+const SNIPPET_LENGTH: usize = 60;
+/// Number of sites listed for every failing selector.
+const LISTED_FAILURES: usize = 3;
+
+/// Matches of a single selector on every checked site.
+#[derive(Default)]
+struct SelectorStats {
+    pages: usize,
+    elements: usize,
+    snippet: Option<String>,
+    failed_pages: Vec<String>,
+}
+
+/// Downloads sample of schedules and games of every league and checks whether selectors
+/// and parsers of the source still work with them.
+pub struct HealthCheck {
+    source: Arc<dyn Source>,
+    leagues: Vec<League>,
+    sample: usize,
+    checks: Vec<(SelectorCheck, SelectorStats)>,
+    // Sites which could not be downloaded or parsed, along with the reason.
+    failures: Vec<(String, String)>,
+    // Games whose results disagree between parts of their site, along with number of
+    // disagreements. They are corrections made on the site, not broken parsers.
+    mismatches: Vec<(String, usize)>,
+}
+
+/// Returns text of element with collapsed whitespace, shortened for the report.
+/// Elements without text, e.g. icons, are shown as HTML.
+fn snippet(element: &ElementRef) -> String {
+    let text = element.text().collect::<Vec<_>>().join(" ");
+    let text = match text.split_whitespace().collect::<Vec<_>>().join(" ") {
+        text if text.is_empty() => element.html(),
+        text => text,
+    };
+
+    if text.chars().count() > SNIPPET_LENGTH {
+        format!("{}...", text.chars().take(SNIPPET_LENGTH).collect::<String>())
+    } else {
+        text
+    }
+}
+
+impl HealthCheck {
+    pub fn new(config: &ProgramConfig, sample: usize) -> Self {
+        let source = config.source();
+        let checks = source
+            .selectors()
+            .into_iter()
+            .map(|check| (check, SelectorStats::default()))
+            .collect();
+
+        Self {
+            source,
+            leagues: config.leagues(),
+            sample,
+            checks,
+            failures: Vec::new(),
+            mismatches: Vec::new(),
+        }
+    }
+
+    /// Runs the check and prints its report. Returns error if anything failed.
+    pub fn run(mut self) -> Result<()> {
+        for league in self.leagues.clone() {
+            if let Err(e) = self.check_league(&league) {
+                self.failures
+                    .push((self.source.schedule_url(&league), format!("{e:#}")));
+            }
+        }
+
+        self.print_report();
+
+        let failed_selectors = self
+            .checks
+            .iter()
+            .filter(|(_, stats)| !stats.failed_pages.is_empty())
+            .count();
+
+        if failed_selectors > 0 || !self.failures.is_empty() {
+            return Err(anyhow!(
+                "Health check failed: {failed_selectors} selectors and {} sites.",
+                self.failures.len()
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn check_league(&mut self, league: &League) -> Result<()> {
+        let schedule_url = self.source.schedule_url(league);
+        let schedule = self.download(&schedule_url, PageKind::Schedule)?;
+
+        let seasons = self
+            .source
+            .parse_seasons(&schedule, league)
+            .context("Unable to parse seasons.")?;
+
+        // Finished games are taken from the latest seasons, until sample is complete.
+        let mut games = Vec::new();
+        for season in seasons {
+            if games.len() >= self.sample {
+                break;
+            }
+
+            let body = if season.site() == schedule_url {
+                schedule.clone()
+            } else {
+                match self.download(season.site(), PageKind::Schedule) {
+                    Ok(body) => body,
+                    Err(e) => {
+                        self.failures.push((season.site().into(), format!("{e:#}")));
+                        continue;
+                    }
+                }
+            };
+
+            match self.source.parse_schedule(&body, league) {
+                Ok(season_games) => games.extend(
+                    season_games
+                        .into_iter()
+                        .filter(|game| game.status() == MatchStatus::Finished),
+                ),
+                Err(e) => self.failures.push((season.site().into(), format!("{e:#}"))),
+            }
+        }
+
+        if games.is_empty() {
+            return Err(anyhow!("Unable to find finished games of league {}.", league.slug()));
+        }
+
+        for game in games.iter().take(self.sample) {
+            let parsed = self
+                .download(game.url(), PageKind::Game)
+                .and_then(|body| self.source.parse_game(&body, game));
+
+            match parsed {
+                Ok(info) => {
+                    if !info.gaps().is_empty() {
+                        self.failures.push((
+                            game.url().into(),
+                            format!("Parsed with {} gaps.", info.gaps().len()),
+                        ));
+                    }
+
+                    if !info.mismatches().is_empty() {
+                        self.mismatches
+                            .push((game.url().into(), info.mismatches().len()));
+                    }
+                }
+                Err(e) => self.failures.push((game.url().into(), format!("{e:#}"))),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Downloads site and matches it against every selector of its kind.
+    fn download(&mut self, url: &str, page: PageKind) -> Result<String> {
+        let body = HttpRequester::make_request(url)?;
+        let parsed_body = Html::parse_document(&body);
+
+        for (check, stats) in self.checks.iter_mut() {
+            if check.page != page {
+                continue;
+            }
+
+            stats.pages += 1;
+
+            let Ok(selector) = Selector::parse(check.selector) else {
+                stats.failed_pages.push(url.into());
+                continue;
+            };
+
+            let mut count = 0;
+            for element in parsed_body.select(&selector) {
+                if stats.snippet.is_none() {
+                    stats.snippet = Some(snippet(&element));
+                }
+
+                count += 1;
+            }
+
+            stats.elements += count;
+            if count < check.min_count {
+                stats.failed_pages.push(url.into());
+            }
+        }
+
+        Ok(body)
+    }
+
+    fn print_report(&self) {
+        println!("Selectors:");
+
+        for (check, stats) in self.checks.iter() {
+            let status = if stats.failed_pages.is_empty() {
+                "OK"
+            } else {
+                "FAIL"
+            };
+
+            println!(
+                "{status:<5} {:<9} {} [{}]: matched {}/{} sites, {} elements",
+                check.page.as_str(),
+                check.name,
+                check.selector,
+                stats.pages - stats.failed_pages.len(),
+                stats.pages,
+                stats.elements,
+            );
+
+            if let Some(snippet) = stats.snippet.as_ref() {
+                println!("      e.g. \"{snippet}\"");
+            }
+
+            for url in stats.failed_pages.iter().take(LISTED_FAILURES) {
+                println!("      missing on {url}");
+            }
+        }
+
+        if !self.failures.is_empty() {
+            println!("Sites:");
+
+            for (url, reason) in self.failures.iter() {
+                println!("FAIL  {url}: {reason}");
+            }
+        }
+
+        if !self.mismatches.is_empty() {
+            println!("Games with results which disagree (not counted as failures):");
+
+            for (url, count) in self.mismatches.iter() {
+                println!("INFO  {url}: {count} mismatches");
+            }
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use std::{
+    collections::HashSet,
+    fs::{File, OpenOptions},
+    io::{BufRead, BufReader, Write},
+    path::Path,
+};
+
+use anyhow::{Context, Result};
+
+const CHECKPOINT_FILE_NAME: &str = "scraping_checkpoint.txt";
+
+/// Registry of game sites which were already scraped and saved to the output file.
+/// Stored as a plain text file with one url per line, so every saved game can be
+/// appended to it without rewriting the whole file.
+pub struct Checkpoint {
+    scraped: HashSet<String>,
+    file: File,
+}
+
+impl Checkpoint {
+    /// Opens checkpoint stored in given folder.
+    /// If `resume` is false, previously stored entries are discarded.
+    pub fn open(folder: &Path, resume: bool) -> Result<Self> {
+        let path = folder.join(CHECKPOINT_FILE_NAME);
+
+        let scraped = if resume && path.is_file() {
+            let file = File::open(&path).with_context(|| {
+                format!("Unable to open checkpoint file {}.", path.display())
+            })?;
+
+            parse_entries(BufReader::new(file))
+                .with_context(|| format!("Unable to read checkpoint file {}.", path.display()))?
+        } else {
+            HashSet::new()
+        };
+
+        let file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(resume)
+            .truncate(!resume)
+            .open(&path)
+            .with_context(|| format!("Unable to create checkpoint file {}.", path.display()))?;
+
+        Ok(Self { scraped, file })
+    }
+
+    /// Returns true if game site was saved by one of previous runs.
+    pub fn contains(&self, url: &str) -> bool {
+        self.scraped.contains(url)
+    }
+
+    /// Marks game site as scraped. Should be called only after its result was flushed to disk.
+    pub fn record(&mut self, url: &str) -> Result<()> {
+        writeln!(self.file, "{url}").context("Unable to write to checkpoint file.")?;
+        self.file
+            .flush()
+            .context("Unable to flush checkpoint file.")?;
+
+        self.scraped.insert(url.into());
+
+        Ok(())
+    }
+}
+
+fn parse_entries<R: BufRead>(reader: R) -> Result<HashSet<String>> {
+    let mut entries = HashSet::new();
+
+    for line in reader.lines() {
+        let line = line?;
+        let url = line.trim();
+
+        if !url.is_empty() {
+            entries.insert(url.into());
+        }
+    }
+
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_entries;
+
+    #[test]
+    fn parsing_entries() {
+        let content = "https://a.pl/1\n\n  https://a.pl/2  \nhttps://a.pl/1\n";
+        let entries = parse_entries(content.as_bytes()).unwrap();
+
+        assert_eq!(entries.len(), 2);
+        assert!(entries.contains("https://a.pl/2"));
+    }
+}
@@ -1,9 +1,24 @@
-use std::{path::PathBuf, time::Duration};
+use std::{path::PathBuf, sync::Arc, time::Duration};
 
-use clap::Parser;
+use clap::{Parser, Subcommand};
+
+use time::Date;
+
+use crate::{
+    filter::{parse_date, parse_season_range, GameFilter, SeasonRange},
+    http::{HttpConfig, DEFAULT_USER_AGENT},
+    league::{League, DEFAULT_LEAGUE},
+    output::OutputFormat,
+    source::{Source, SourceKind},
+};
 
 #[derive(Parser, Debug)]
-#[clap(author, version, about = "Polish speedway match results scraper.")]
+#[clap(
+    author,
+    version,
+    about = "Polish speedway match results scraper.",
+    subcommand_negates_reqs = true
+)]
 pub struct ProgramConfig {
     /// Sets number of threads to send HTTP requests.
     #[arg(default_value_t = 2, long, value_name = "THREADS")]
@@ -11,11 +26,92 @@ pub struct ProgramConfig {
 
     /// Location of output files
     #[arg(long, short = 'o', required = true, value_name = "OUT_FILE")]
-    output_folder: PathBuf,
+    output_folder: Option<PathBuf>,
 
-    /// Minimal duration between sending HTTP requests (in milliseconds as integer number).
-    #[arg(long, short = 'i', value_name = "INTERVAL", default_value_t = 100)]
+    /// Minimal duration between sending HTTP requests to the same host (in milliseconds as integer number).
+    #[arg(long, short = 'i', value_name = "INTERVAL", default_value_t = 100, global = true)]
     tick_interval: u64,
+
+    /// Number of attempts of every HTTP request before giving up.
+    #[arg(long, value_name = "ATTEMPTS", default_value_t = 5, value_parser = clap::value_parser!(u32).range(1..), global = true)]
+    max_attempts: u32,
+
+    /// Delay before retrying failed HTTP request (in milliseconds), doubled with every attempt.
+    #[arg(long, value_name = "DELAY", default_value_t = 500, global = true)]
+    retry_delay: u64,
+
+    /// User agent sent with HTTP requests and matched against robots.txt.
+    #[arg(long, value_name = "USER_AGENT", default_value = DEFAULT_USER_AGENT, global = true)]
+    user_agent: String,
+
+    /// Skips games saved by previous runs and appends new results to existing output.
+    #[arg(long)]
+    incremental: bool,
+
+    /// Location of stored HTTP responses. Every downloaded site is saved there.
+    #[arg(long, value_name = "CACHE_FOLDER", global = true)]
+    cache_folder: Option<PathBuf>,
+
+    /// Serves every request from the cache folder without connecting to the site.
+    #[arg(long, requires = "cache_folder", global = true)]
+    offline: bool,
+
+    /// Site with results of games.
+    #[arg(long, value_enum, default_value_t = SourceKind::Sportowefakty, global = true)]
+    source: SourceKind,
+
+    /// League to scrape, as named in site urls (e.g. pge-ekstraliga, ewinner-1-liga).
+    /// Can be given multiple times.
+    #[arg(long = "league", value_name = "LEAGUE", default_value = DEFAULT_LEAGUE, global = true)]
+    leagues: Vec<String>,
+
+    /// Season to scrape, given as a year (2022) or an inclusive range (2015-2018).
+    /// Can be given multiple times. Every season is scraped by default.
+    #[arg(long = "season", value_name = "SEASONS", value_parser = parse_season_range)]
+    seasons: Vec<SeasonRange>,
+
+    /// Skips games played before given date (YYYY-MM-DD).
+    #[arg(long, value_name = "DATE", value_parser = parse_date)]
+    from: Option<Date>,
+
+    /// Skips games played after given date (YYYY-MM-DD).
+    #[arg(long, value_name = "DATE", value_parser = parse_date)]
+    to: Option<Date>,
+
+    /// Format of output file with scraped games.
+    #[arg(long, value_enum, default_value_t = OutputFormat::Ndjson)]
+    format: OutputFormat,
+
+    /// Postgres connection string. Scraped games are loaded into database as they are parsed.
+    #[arg(long, value_name = "URL")]
+    database_url: Option<String>,
+
+    /// Skips writing file with scraped games. Checkpoint and error report are still saved.
+    #[arg(long, requires = "database_url")]
+    no_output: bool,
+
+    /// Keeps polling schedule of the current season and loads games which are played
+    /// or have just finished into database.
+    #[arg(long, requires = "database_url", conflicts_with = "seasons")]
+    watch: bool,
+
+    /// Duration between polls of the schedule in watch mode (in seconds).
+    #[arg(long, value_name = "SECONDS", default_value_t = 60)]
+    poll_interval: u64,
+
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Downloads sample of sites and checks whether every selector of the parsers still
+    /// matches them. Exits with error if any selector or parser fails.
+    Check {
+        /// Number of game sites checked in every league.
+        #[arg(long, value_name = "GAMES", default_value_t = 5)]
+        sample: usize,
+    },
 }
 
 impl ProgramConfig {
@@ -26,11 +122,73 @@ impl ProgramConfig {
 
     /// Returns path to output file.
     pub fn output_folder(&self) -> PathBuf {
-        self.output_folder.clone()
+        self.output_folder
+            .clone()
+            .expect("Output folder is required without subcommand.")
+    }
+
+    /// Returns settings of http requests.
+    pub fn http_config(&self) -> HttpConfig {
+        HttpConfig {
+            tick_interval: Duration::from_millis(self.tick_interval),
+            max_attempts: self.max_attempts,
+            retry_delay: Duration::from_millis(self.retry_delay),
+            user_agent: self.user_agent.clone(),
+        }
+    }
+
+    /// Returns true if scraping should resume from the checkpoint of previous runs.
+    pub fn incremental(&self) -> bool {
+        self.incremental
+    }
+
+    /// Returns path to folder with cached HTTP responses.
+    pub fn cache_folder(&self) -> Option<PathBuf> {
+        self.cache_folder.clone()
+    }
+
+    /// Returns true if program should work only on cached HTTP responses.
+    pub fn offline(&self) -> bool {
+        self.offline
+    }
+
+    /// Returns site which should be scraped.
+    pub fn source(&self) -> Arc<dyn Source> {
+        self.source.source()
+    }
+
+    /// Returns leagues which should be scraped.
+    pub fn leagues(&self) -> Vec<League> {
+        self.leagues.iter().map(League::new).collect()
+    }
+
+    /// Returns selection of seasons and dates which should be scraped.
+    pub fn filter(&self) -> GameFilter {
+        GameFilter::new(self.seasons.clone(), self.from, self.to)
+    }
+
+    /// Returns format of output file, or `None` if file should not be written.
+    pub fn format(&self) -> Option<OutputFormat> {
+        (!self.no_output).then_some(self.format)
+    }
+
+    /// Returns true if program should keep watching games of the current season.
+    pub fn watch(&self) -> bool {
+        self.watch
+    }
+
+    /// Returns duration between polls of the schedule in watch mode.
+    pub fn poll_interval(&self) -> Duration {
+        Duration::from_secs(self.poll_interval)
+    }
+
+    /// Returns connection string of database which scraped games are loaded into.
+    pub fn database_url(&self) -> Option<String> {
+        self.database_url.clone()
     }
 
-    /// Returns required interval between http requests.
-    pub fn tick_interval(&self) -> Duration {
-        Duration::from_millis(self.tick_interval)
+    /// Returns command to run instead of scraping.
+    pub fn command(&self) -> Option<&Command> {
+        self.command.as_ref()
     }
 }
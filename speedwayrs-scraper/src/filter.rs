@@ -0,0 +1,109 @@
+use time::{macros::format_description, Date, PrimitiveDateTime};
+
+/// Inclusive range of season years.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeasonRange {
+    first: u32,
+    last: u32,
+}
+
+impl SeasonRange {
+    pub fn contains(&self, year: u32) -> bool {
+        self.first <= year && year <= self.last
+    }
+}
+
+/// Parses season given as a single year (`2022`) or as a range (`2015-2018`).
+pub fn parse_season_range(text: &str) -> Result<SeasonRange, String> {
+    let parse_year = |year: &str| {
+        year.trim()
+            .parse::<u32>()
+            .map_err(|_| format!("Invalid season year [{year}]."))
+    };
+
+    let (first, last) = match text.split_once('-') {
+        Some((first, last)) => (parse_year(first)?, parse_year(last)?),
+        None => {
+            let year = parse_year(text)?;
+
+            (year, year)
+        }
+    };
+
+    if first > last {
+        return Err(format!("Season range [{text}] is empty."));
+    }
+
+    Ok(SeasonRange { first, last })
+}
+
+/// Parses date given as `YYYY-MM-DD`.
+pub fn parse_date(text: &str) -> Result<Date, String> {
+    Date::parse(text.trim(), format_description!("[year]-[month]-[day]"))
+        .map_err(|e| format!("Invalid date [{text}], expected YYYY-MM-DD. {e}"))
+}
+
+/// Selection of games which should be scraped.
+#[derive(Debug, Clone, Default)]
+pub struct GameFilter {
+    seasons: Vec<SeasonRange>,
+    from: Option<Date>,
+    to: Option<Date>,
+}
+
+impl GameFilter {
+    pub fn new(seasons: Vec<SeasonRange>, from: Option<Date>, to: Option<Date>) -> Self {
+        Self { seasons, from, to }
+    }
+
+    /// Returns true if season can contain selected games.
+    /// Used to skip schedules and game sites of other seasons before they are downloaded.
+    pub fn accepts_season(&self, year: u32) -> bool {
+        let selected = self.seasons.is_empty() || self.seasons.iter().any(|s| s.contains(year));
+        let after_from = self.from.is_none_or(|from| year as i32 >= from.year());
+        let before_to = self.to.is_none_or(|to| year as i32 <= to.year());
+
+        selected && after_from && before_to
+    }
+
+    /// Returns true if game played at given date is selected.
+    pub fn accepts_date(&self, date: &PrimitiveDateTime) -> bool {
+        let after_from = self.from.is_none_or(|from| date.date() >= from);
+        let before_to = self.to.is_none_or(|to| date.date() <= to);
+
+        after_from && before_to
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::{date, datetime};
+
+    use super::{parse_date, parse_season_range, GameFilter};
+
+    #[test]
+    fn parsing_season_range() {
+        let single = parse_season_range("2022").unwrap();
+        let range = parse_season_range("2015-2018").unwrap();
+
+        assert!(single.contains(2022) && !single.contains(2021));
+        assert!(range.contains(2015) && range.contains(2018) && !range.contains(2019));
+        assert!(parse_season_range("2018-2015").is_err());
+        assert!(parse_season_range("sezon").is_err());
+    }
+
+    #[test]
+    fn filtering_games() {
+        let filter = GameFilter::new(
+            vec![parse_season_range("2020-2022").unwrap()],
+            Some(parse_date("2021-05-01").unwrap()),
+            None,
+        );
+
+        assert!(!filter.accepts_season(2020));
+        assert!(filter.accepts_season(2022));
+        assert!(!filter.accepts_season(2023));
+        assert!(filter.accepts_date(&datetime!(2021-05-01 19:00)));
+        assert!(!filter.accepts_date(&date!(2021-04-30).midnight()));
+    }
+}
@@ -0,0 +1,62 @@
+use speedwayrs_types::{scraper_types::Fixture, MatchStatus};
+
+use crate::league::League;
+
+/// Game listed in schedule of a league, found by one of the sources.
+#[derive(Debug)]
+pub struct GameSite {
+    url: String,
+    league: League,
+    status: MatchStatus,
+    teams: Option<(String, String)>,
+    date: Option<time::PrimitiveDateTime>,
+}
+
+impl GameSite {
+    pub fn new(
+        url: String,
+        league: &League,
+        status: MatchStatus,
+        teams: Option<(String, String)>,
+        date: Option<time::PrimitiveDateTime>,
+    ) -> Self {
+        Self {
+            url,
+            league: league.clone(),
+            status,
+            teams,
+            date,
+        }
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn league(&self) -> &League {
+        &self.league
+    }
+
+    pub fn status(&self) -> MatchStatus {
+        self.status
+    }
+
+    /// Returns date of game listed in schedule, if schedule shows it.
+    pub fn date(&self) -> Option<&time::PrimitiveDateTime> {
+        self.date.as_ref()
+    }
+
+    /// Returns game as a fixture, if schedule lists its teams and date.
+    pub fn fixture(&self) -> Option<Fixture> {
+        let (team1, team2) = self.teams.clone()?;
+
+        Some(Fixture::new(
+            self.league.slug().into(),
+            self.url.clone(),
+            team1,
+            team2,
+            self.date?,
+            self.status,
+        ))
+    }
+}
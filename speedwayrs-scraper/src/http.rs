@@ -1,37 +1,98 @@
 use std::{
+    collections::HashMap,
     sync::{Arc, Mutex},
-    time::{Duration, SystemTime},
+    time::{Duration, Instant, SystemTime},
 };
 
 use anyhow::{anyhow, Context, Result};
 use once_cell::sync::OnceCell;
+use reqwest::{header::RETRY_AFTER, Client, Response, StatusCode, Url};
+use tokio::runtime::Runtime;
 
-static REQUESTER: OnceCell<Arc<Mutex<HttpRequester>>> = OnceCell::new();
+use crate::{cache::ResponseCache, robots::Robots};
+
+static REQUESTER: OnceCell<HttpRequester> = OnceCell::new();
 pub const DEFAULT_TICK_INTERVAL: Duration = Duration::from_millis(1);
-const CONNECT_SLEEPING_DURATION: Duration = Duration::from_secs(3);
+pub const DEFAULT_USER_AGENT: &str = concat!("speedwayrs-scraper/", env!("CARGO_PKG_VERSION"));
+/// Backoff between attempts does not grow over this duration.
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(60);
 
-/// Struct used as http request limiter.
-/// Only thread which have this struct can make http requests.
-/// Used to not over overwhelm server with pings.
-pub struct HttpRequester {
-    last_request: Option<SystemTime>,
-    tick_interval: Duration,
+/// Settings of HTTP requests sent by the scraper.
+#[derive(Debug, Clone)]
+pub struct HttpConfig {
+    /// Minimal duration between requests sent to the same host.
+    pub tick_interval: Duration,
+    /// Number of attempts of every request, including the first one.
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled with every next attempt.
+    pub retry_delay: Duration,
+    pub user_agent: String,
 }
 
-impl HttpRequester {
-    fn new(tick_interval: Duration) -> Self {
+impl Default for HttpConfig {
+    fn default() -> Self {
         Self {
-            last_request: None,
-            tick_interval,
+            tick_interval: DEFAULT_TICK_INTERVAL,
+            max_attempts: 5,
+            retry_delay: Duration::from_millis(500),
+            user_agent: DEFAULT_USER_AGENT.into(),
         }
     }
+}
+
+/// Spacing of requests sent to a single host.
+struct Schedule {
+    last_request: Option<Instant>,
+    interval: Duration,
+}
+
+struct Host {
+    schedule: tokio::sync::Mutex<Schedule>,
+    // Loaded before the first request to the host.
+    robots: tokio::sync::OnceCell<Robots>,
+}
+
+/// Result of a single attempt which can be repeated.
+enum Attempt {
+    Done(String),
+    /// Request should be sent again, after given delay if server asked for it.
+    Retry(anyhow::Error, Option<Duration>),
+}
 
-    fn default_new() -> Self {
-        Self::new(DEFAULT_TICK_INTERVAL)
+/// HTTP client shared by every thread of the scraper.
+/// Requests to every host are spaced by tick interval, or by crawl delay of its robots.txt
+/// if it is longer, while different hosts are requested independently.
+pub struct HttpRequester {
+    runtime: Runtime,
+    client: Client,
+    config: HttpConfig,
+    hosts: Mutex<HashMap<String, Arc<Host>>>,
+}
+
+impl HttpRequester {
+    fn new(config: HttpConfig) -> Result<Self> {
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(2)
+            .enable_all()
+            .build()
+            .context("Unable to build tokio runtime.")?;
+
+        let client = Client::builder()
+            .user_agent(config.user_agent.as_str())
+            .build()
+            .context("Unable to build HTTP client.")?;
+
+        Ok(Self {
+            runtime,
+            client,
+            config,
+            hosts: Mutex::new(HashMap::new()),
+        })
     }
 
-    pub fn set_tick_interval(tick_interval: Duration) -> Result<()> {
-        let set_result = REQUESTER.set(Arc::new(Mutex::new(Self::new(tick_interval))));
+    /// Installs client used by every HTTP request made by the program.
+    pub fn install(config: HttpConfig) -> Result<()> {
+        let set_result = REQUESTER.set(Self::new(config)?);
 
         if set_result.is_err() {
             Err(anyhow!("HttpRequester was already initialized!"))
@@ -40,47 +101,229 @@ impl HttpRequester {
         }
     }
 
-    fn mutex() -> Arc<Mutex<Self>> {
-        REQUESTER
-            .get_or_init(|| Arc::new(Mutex::new(HttpRequester::default_new())))
-            .clone()
+    fn installed() -> &'static Self {
+        REQUESTER.get_or_init(|| {
+            Self::new(HttpConfig::default()).expect("Unable to create HTTP client.")
+        })
     }
 
+    /// Returns body of site, served from response cache if one is installed.
+    /// Blocks until the site is downloaded, so it should not be called from asynchronous code.
     pub fn make_request(url: &str) -> Result<String> {
-        {
-            let self_mutex = Self::mutex();
-            let mut self_lock = self_mutex.lock().unwrap();
+        let cache = ResponseCache::installed();
+
+        if let Some(cache) = cache.filter(|cache| cache.offline()) {
+            return cache
+                .load(url)?
+                .with_context(|| format!("Site [{url}] is not present in offline cache."));
+        }
+
+        let requester = Self::installed();
+        let body = requester.runtime.block_on(requester.fetch(url))?;
 
-            if let Some(last_time) = self_lock.last_request {
-                let elapsed_time = last_time.elapsed()?;
+        if let Some(cache) = cache {
+            cache.store(url, &body)?;
+        }
+
+        Ok(body)
+    }
+
+    fn host(&self, origin: &str) -> Arc<Host> {
+        self.hosts
+            .lock()
+            .unwrap()
+            .entry(origin.into())
+            .or_insert_with(|| {
+                Arc::new(Host {
+                    schedule: tokio::sync::Mutex::new(Schedule {
+                        last_request: None,
+                        interval: self.config.tick_interval,
+                    }),
+                    robots: tokio::sync::OnceCell::new(),
+                })
+            })
+            .clone()
+    }
+
+    /// Waits until the next request can be sent to the host.
+    async fn wait_for_turn(host: &Host) {
+        // Lock is held while sleeping, so requests to the host are sent one by one.
+        let mut schedule = host.schedule.lock().await;
+
+        if let Some(last_request) = schedule.last_request {
+            let elapsed = last_request.elapsed();
 
-                if elapsed_time < self_lock.tick_interval {
-                    std::thread::sleep(self_lock.tick_interval - elapsed_time);
-                }
+            if elapsed < schedule.interval {
+                tokio::time::sleep(schedule.interval - elapsed).await;
             }
+        }
+
+        schedule.last_request = Some(Instant::now());
+    }
+
+    /// Returns robots.txt of the host, downloading it on the first call.
+    /// Missing or unavailable robots.txt allows every site.
+    async fn robots<'a>(&self, url: &Url, host: &'a Host) -> &'a Robots {
+        host.robots
+            .get_or_init(|| async {
+                let robots_url = url.join("/robots.txt").expect("Path of robots.txt is valid.");
+
+                Self::wait_for_turn(host).await;
+                let content = match self.client.get(robots_url.clone()).send().await {
+                    Ok(response) if response.status().is_success() => response.text().await.ok(),
+                    Ok(_) => None,
+                    Err(e) => {
+                        eprintln!("WARNING: Unable to download {robots_url} = [{e:?}]");
+
+                        None
+                    }
+                };
+
+                let robots = content
+                    .map(|content| Robots::parse(&content, &self.config.user_agent))
+                    .unwrap_or_default();
+
+                let mut schedule = host.schedule.lock().await;
+                schedule.interval = schedule
+                    .interval
+                    .max(robots.crawl_delay().unwrap_or_default());
+
+                robots
+            })
+            .await
+    }
+
+    async fn fetch(&self, url: &str) -> Result<String> {
+        let parsed_url = Url::parse(url).with_context(|| format!("Invalid url [{url}]."))?;
+        let host = self.host(&parsed_url.origin().ascii_serialization());
 
-            self_lock.last_request = Some(SystemTime::now());
+        let path = match parsed_url.query() {
+            Some(query) => format!("{}?{query}", parsed_url.path()),
+            None => parsed_url.path().to_string(),
+        };
+
+        if !self.robots(&parsed_url, &host).await.allows(&path) {
+            return Err(anyhow!("Site [{url}] is disallowed by robots.txt."));
         }
 
+        let mut attempt = 1;
+
         loop {
-            match reqwest::blocking::get(url) {
-                Ok(site) => {
-                    let body = site
-                        .text()
-                        .with_context(|| format!("Unable to get body of site [{url}]."))?;
-
-                    return Ok(body);
-                }
-
-                Err(e) => {
-                    if e.is_connect() {
-                        std::thread::sleep(CONNECT_SLEEPING_DURATION)
-                    } else {
-                        return Err(e)
-                            .with_context(|| format!("Unable to connect with site [{url}]."));
-                    }
-                }
+            Self::wait_for_turn(&host).await;
+
+            let (error, retry_after) = match self.attempt(url).await? {
+                Attempt::Done(body) => return Ok(body),
+                Attempt::Retry(error, retry_after) => (error, retry_after),
+            };
+
+            if attempt >= self.config.max_attempts {
+                return Err(error).with_context(|| {
+                    format!("Unable to download site [{url}] in {attempt} attempts.")
+                });
+            }
+
+            tokio::time::sleep(retry_after.unwrap_or_else(|| self.backoff(attempt))).await;
+            attempt += 1;
+        }
+    }
+
+    /// Sends request once. Failures which can pass by themselves are returned as `Retry`,
+    /// other failures as errors.
+    async fn attempt(&self, url: &str) -> Result<Attempt> {
+        let response = match self.client.get(url).send().await {
+            Ok(response) => response,
+            Err(e) if e.is_connect() || e.is_timeout() || e.is_request() => {
+                let error = anyhow::Error::new(e)
+                    .context(format!("Unable to connect with site [{url}]."));
+
+                return Ok(Attempt::Retry(error, None));
+            }
+            Err(e) => {
+                return Err(e).with_context(|| format!("Unable to connect with site [{url}]."))
             }
+        };
+
+        let status = response.status();
+
+        if status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error() {
+            let retry_after = retry_after(&response);
+            let error = anyhow!("Site [{url}] responded with status {status}.");
+
+            return Ok(Attempt::Retry(error, retry_after));
         }
+
+        if !status.is_success() {
+            return Err(anyhow!("Site [{url}] responded with status {status}."));
+        }
+
+        match response.text().await {
+            Ok(body) => Ok(Attempt::Done(body)),
+            Err(e) => {
+                let error =
+                    anyhow::Error::new(e).context(format!("Unable to get body of site [{url}]."));
+
+                Ok(Attempt::Retry(error, None))
+            }
+        }
+    }
+
+    /// Returns delay before the next attempt. Delay is doubled after every attempt,
+    /// and randomized, so threads which failed together do not retry together.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let delay = self
+            .config
+            .retry_delay
+            .saturating_mul(2u32.saturating_pow(attempt - 1))
+            .min(MAX_RETRY_DELAY);
+
+        delay / 2 + delay.mul_f64(fastrand::f64() / 2.0)
+    }
+}
+
+/// Reads `Retry-After` header given as number of seconds or as date.
+fn retry_after(response: &Response) -> Option<Duration> {
+    let value = response.headers().get(RETRY_AFTER)?.to_str().ok()?;
+
+    parse_retry_after(value, SystemTime::now())
+}
+
+/// Delay requested by site is capped as computed delays are, so a single response
+/// cannot stop worker for hours.
+fn parse_retry_after(value: &str, now: SystemTime) -> Option<Duration> {
+    let value = value.trim();
+
+    let delay = match value.parse() {
+        Ok(seconds) => Duration::from_secs(seconds),
+        Err(_) => {
+            let date = httpdate::parse_http_date(value).ok()?;
+
+            date.duration_since(now).unwrap_or_default()
+        }
+    };
+
+    Some(delay.min(MAX_RETRY_DELAY))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use super::{parse_retry_after, MAX_RETRY_DELAY};
+
+    #[test]
+    fn retry_after_is_capped() {
+        let now = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000_000);
+
+        assert_eq!(parse_retry_after("5", now), Some(Duration::from_secs(5)));
+        assert_eq!(parse_retry_after("86400", now), Some(MAX_RETRY_DELAY));
+        assert_eq!(
+            parse_retry_after("Sun, 09 Sep 2001 01:46:50 GMT", now),
+            Some(Duration::from_secs(10))
+        );
+        assert_eq!(
+            parse_retry_after("Fri, 01 Jan 2100 00:00:00 GMT", now),
+            Some(MAX_RETRY_DELAY)
+        );
+        assert_eq!(parse_retry_after("soon", now), None);
     }
 }
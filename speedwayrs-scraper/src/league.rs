@@ -0,0 +1,17 @@
+pub const DEFAULT_LEAGUE: &str = "pge-ekstraliga";
+
+/// League identified by its name used in site urls, e.g. `pge-ekstraliga`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct League {
+    slug: String,
+}
+
+impl League {
+    pub fn new<T: Into<String>>(slug: T) -> Self {
+        Self { slug: slug.into() }
+    }
+
+    pub fn slug(&self) -> &str {
+        &self.slug
+    }
+}
@@ -1,13 +1,20 @@
+mod cache;
+mod check;
+mod checkpoint;
 mod config;
 mod file;
+mod filter;
 mod game;
 mod http;
+mod league;
 mod manager;
+mod output;
+mod reconcile;
+mod report;
+mod robots;
 mod season;
-
-const MAIN_SITE: &str = "https://sportowefakty.wp.pl/zuzel/pge-ekstraliga/terminarz";
-const RELATIVE_MAIN: &str = "/zuzel/pge-ekstraliga/terminarz";
-const BASE_SITE: &str = "https://sportowefakty.wp.pl";
+mod source;
+mod watch;
 
 use std::time::Duration;
 
@@ -16,17 +23,30 @@ use clap::Parser;
 use manager::Manager;
 use scraper::{Html, Selector};
 
-use crate::config::ProgramConfig;
+use crate::config::{Command, ProgramConfig};
 
 const CONNECT_SLEEPING_DURATION: Duration = Duration::from_secs(3);
 const REQUEST_PAUSE_DURATION: Duration = Duration::from_millis(50);
 
 fn main() -> Result<()> {
     let config = ProgramConfig::parse();
-    http::HttpRequester::set_tick_interval(config.tick_interval())?;
+    http::HttpRequester::install(config.http_config())?;
+
+    if let Some(cache_folder) = config.cache_folder() {
+        file::check_folder(cache_folder.clone())?;
+        cache::ResponseCache::install(cache_folder, config.offline())?;
+    }
+
+    if let Some(Command::Check { sample }) = config.command() {
+        return check::HealthCheck::new(&config, *sample).run();
+    }
 
     file::check_folder(config.output_folder())?;
 
+    if config.watch() {
+        return watch::Watcher::new(&config)?.run();
+    }
+
     let manager = manager::Manager::new(&config);
     manager.begin_scraping()?;
 
@@ -1,17 +1,22 @@
 use crate::{
-    game::{GameSite, ScraperGameInfo},
-    season::Season,
+    checkpoint::Checkpoint,
+    filter::GameFilter,
+    game::GameSite,
+    league::League,
+    output::{OutputFormat, OutputWriter},
+    report::{ErrorEntry, ErrorReport, MismatchEntry, ScrapingStage},
+    source::Source,
 };
 use std::{
-    fs::File,
-    io::{BufWriter, Write},
+    collections::HashSet,
     path::PathBuf,
     sync::{mpsc::Receiver, RwLock, Arc},
 };
 
-use anyhow::{Context, Result};
+use anyhow::{anyhow, Context, Result};
 use indicatif::{ProgressStyle, ProgressState, ProgressBar};
-use scraper::Html;
+use speedwayrs_loader::pipeline::Pipeline;
+use speedwayrs_types::scraper_types::GameInfo;
 use threadpool::ThreadPool;
 
 use crate::config::ProgramConfig;
@@ -19,99 +24,223 @@ use crate::http::HttpRequester;
 
 pub struct Manager {
     pool: ThreadPool,
+    source: Arc<dyn Source>,
     output_folder: PathBuf,
+    incremental: bool,
+    leagues: Vec<League>,
+    filter: GameFilter,
+    format: Option<OutputFormat>,
+    database_url: Option<String>,
 }
 
-const FILE_NAME: &'static str = "scraping_result.json";
-
 impl Manager {
     pub fn new(config: &ProgramConfig) -> Self {
         Self {
             pool: ThreadPool::new(config.concurrency()),
+            source: config.source(),
             output_folder: config.output_folder(),
+            incremental: config.incremental(),
+            leagues: config.leagues(),
+            filter: config.filter(),
+            format: config.format(),
+            database_url: config.database_url(),
         }
     }
 
     fn read_game_sites(&self) -> Result<Vec<GameSite>> {
-        // Returns a vector of seasons along with their site links.
-        let seasons = Season::parse_site()?;
         let mut games = Vec::new();
 
-        for season in seasons {
-            let season_source = HttpRequester::make_request(season.site())?;
-            let season_html = Html::parse_document(&season_source);
+        for league in self.leagues.iter() {
+            // Returns a vector of seasons along with their site links.
+            let seasons = HttpRequester::make_request(&self.source.schedule_url(league))
+                .and_then(|body| self.source.parse_seasons(&body, league))
+                .with_context(|| format!("Unable to read seasons of league {}.", league.slug()))?;
+
+            for season in seasons {
+                if !self.filter.accepts_season(season.year()) {
+                    continue;
+                }
 
-            let mut game_info = GameSite::parse_match_schedule(&season_html)?;
+                let season_source = HttpRequester::make_request(season.site())?;
+                let mut game_info = self.source.parse_schedule(&season_source, league)?;
 
-            games.append(&mut game_info);
+                games.append(&mut game_info);
+            }
         }
 
         Ok(games)
     }
 
+    /// Loads fixtures into database. Without database they are only counted.
+    fn save_fixtures(&self, games: Vec<GameSite>, pipeline: Option<&Pipeline>) {
+        let fixtures: Vec<_> = games
+            .iter()
+            .filter_map(GameSite::fixture)
+            .filter(|fixture| self.filter.accepts_date(fixture.date()))
+            .collect();
+
+        match pipeline {
+            Some(pipeline) => {
+                eprintln!("Loading {} fixtures into database.", fixtures.len());
+
+                fixtures
+                    .into_iter()
+                    .for_each(|fixture| pipeline.send_fixture(fixture));
+            }
+            None if !games.is_empty() => {
+                eprintln!("Skipping {} games which were not played yet.", games.len());
+            }
+            None => {}
+        }
+    }
+
     pub fn begin_scraping(&self) -> Result<()> {
         let count = Arc::new(RwLock::new(0));
-        let end = Arc::new(RwLock::new(false));
-
-        let mut file_path = self.output_folder.clone();
-        file_path.push(FILE_NAME);
 
-        let file_link = std::fs::File::create(file_path)
+        let output = self
+            .format
+            .map(|format| OutputWriter::open(&self.output_folder, format, self.incremental))
+            .transpose()
             .context("Unable to create file with parsing results.")?;
 
+        let pipeline = self
+            .database_url
+            .as_deref()
+            .map(Pipeline::connect)
+            .transpose()
+            .context("Unable to connect to database.")?;
+
+        let checkpoint = Checkpoint::open(&self.output_folder, self.incremental)?;
+
         let (tx, rx) = std::sync::mpsc::channel();
 
-        let end_rw = end.clone();
         let count_arc = count.clone();
-        let saving_job = move |pb: ProgressBar, file: File, receiver: Receiver<Result<ScraperGameInfo>>| {
-            let mut file_buffer = BufWriter::new(file);
-
-            while let Ok(info) = receiver.recv() {
-                if let Ok(info_ok) = info {
-                    let serialized_info = serde_json::to_string_pretty(&info_ok).unwrap();
+        let filter = self.filter.clone();
+        let saving_job = move |pb: ProgressBar,
+                               mut output: Option<OutputWriter>,
+                               pipeline: Option<Pipeline>,
+                               mut checkpoint: Checkpoint,
+                               receiver: Receiver<(GameSite, Result<GameInfo>)>| {
+            let mut report = ErrorReport::default();
+            let mut skipped = 0;
+            // Games loaded into database are recorded in checkpoint once the pipeline confirms them.
+            let mut awaiting = HashSet::new();
 
-                    if let Err(e) = file_buffer.write_all(serialized_info.as_bytes()) {
-                        eprintln!("ERROR: While reading to file = [{:?}]", e);
+            while let Ok((game, info)) = receiver.recv() {
+                match info {
+                    // Games without date in schedule can be filtered only after parsing.
+                    Ok(info_ok)
+                        if game.date().is_none() && !filter.accepts_date(info_ok.date()) =>
+                    {
+                        skipped += 1;
                     }
+                    Ok(info_ok) => {
+                        if !info_ok.mismatches().is_empty() {
+                            report.push_mismatches(MismatchEntry::new(
+                                game.url(),
+                                info_ok.mismatches(),
+                            ));
+                        }
 
-                    {
-                        let mut count_write = count_arc.write().unwrap();
-                        *count_write += 1;
-                        pb.set_position(*count_write);
+                        let written = match output.as_mut().map(|output| output.write(&info_ok)) {
+                            Some(Err(e)) => {
+                                eprintln!("ERROR: While reading to file = [{:?}]", e);
+
+                                false
+                            }
+                            _ => true,
+                        };
+
+                        match pipeline.as_ref() {
+                            Some(pipeline) => {
+                                if written {
+                                    awaiting.insert(game.url().to_string());
+                                }
+
+                                pipeline.send(info_ok);
+                            }
+                            None if written => {
+                                if let Err(e) = checkpoint.record(game.url()) {
+                                    eprintln!("ERROR: While updating checkpoint = [{:?}]", e);
+                                }
+                            }
+                            None => {}
+                        }
+                    }
+                    Err(e) => {
+                        report.push(ErrorEntry::new(game.url(), &e));
                     }
-                } else {
-                    eprintln!("Wczytano error: {:?}\n", info);
+                }
+
+                {
+                    let mut count_write = count_arc.write().unwrap();
+                    *count_write += 1;
+                    pb.set_position(*count_write);
                 }
             }
 
-            let _ = file_buffer.flush();
-            *end_rw.write().unwrap() = true;
+            pb.finish();
+
+            let summary = pipeline.map(|pipeline| {
+                let (summary, loaded) = pipeline.finish();
+
+                for url in loaded.iter().filter(|url| awaiting.contains(*url)) {
+                    if let Err(e) = checkpoint.record(url) {
+                        eprintln!("ERROR: While updating checkpoint = [{:?}]", e);
+                    }
+                }
+
+                summary
+            });
+
+            (report, skipped, summary)
         };
 
         let games = self.read_game_sites()?;
 
+        // Games which were not played have nothing to scrape. They are stored as fixtures.
+        let (mut games, fixtures): (Vec<_>, Vec<_>) =
+            games.into_iter().partition(|game| game.status().is_played());
+        self.save_fixtures(fixtures, pipeline.as_ref());
+
+        let discovered = games.len();
+        games.retain(|game| !checkpoint.contains(game.url()));
+
+        if self.incremental {
+            eprintln!(
+                "Skipping {} games saved by previous runs.",
+                discovered - games.len()
+            );
+        }
+
+        // Sites of games played outside of selected dates are not downloaded at all.
+        let selected = games.len();
+        games.retain(|game| game.date().is_none_or(|date| self.filter.accepts_date(date)));
+        let skipped_before_download = selected - games.len();
+
         let pb = ProgressBar::new(games.len().try_into().unwrap());
         pb.set_style(ProgressStyle::with_template("{spinner:.green} [{elapsed_precise}] [{wide_bar:.cyan/blue}] {bytes}/{total_bytes} ({eta})")
             .unwrap()
             .with_key("eta", |state: &ProgressState, w: &mut dyn std::fmt::Write| write!(w, "{:.1}s", state.eta().as_secs_f64()).unwrap())
             .progress_chars("#>-"));
-            
-        std::thread::spawn(move || saving_job(pb, file_link, rx));
 
+        let saving_handle = std::thread::spawn(move || saving_job(pb, output, pipeline, checkpoint, rx));
 
         for game in games {
             let tx_clone = tx.clone();
+            let source = self.source.clone();
 
             self.pool.execute(move || {
-                let game_source = HttpRequester::make_request(game.url());
+                let game_source =
+                    HttpRequester::make_request(game.url()).context(ScrapingStage::Download);
 
                 match game_source {
                     Err(e) => {
-                        tx_clone.send(Err(e)).unwrap();
+                        tx_clone.send((game, Err(e))).unwrap();
                     }
-                    Ok(source) => {
-                        let game_info = ScraperGameInfo::parse_site(&source, game.url());
-                        tx_clone.send(game_info).unwrap();
+                    Ok(body) => {
+                        let game_info = source.parse_game(&body, &game);
+                        tx_clone.send((game, game_info)).unwrap();
                     }
                 }
             });
@@ -119,6 +248,42 @@ impl Manager {
 
         self.pool.join();
 
+        // Saving job finishes once every sender is dropped.
+        drop(tx);
+        let (report, skipped, summary) = saving_handle
+            .join()
+            .map_err(|_| anyhow!("Saving thread panicked."))?;
+
+        let skipped = skipped + skipped_before_download;
+
+        if skipped > 0 {
+            eprintln!("Skipped {skipped} games played outside of selected dates.");
+        }
+
+        report.save(&self.output_folder)?;
+
+        if !report.is_empty() {
+            eprintln!(
+                "Unable to scrape {} games. Details were saved to the error report.",
+                report.len()
+            );
+        }
+
+        if report.mismatched_games() > 0 {
+            eprintln!(
+                "Lineups of {} games disagree with their heats or final score. Details were saved to the mismatch report.",
+                report.mismatched_games()
+            );
+        }
+
+        if let Some(summary) = summary {
+            eprintln!("{summary}");
+
+            if summary.failed > 0 {
+                return Err(anyhow!("Unable to load {} games into database.", summary.failed));
+            }
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,188 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::{BufWriter, Read, Seek, SeekFrom, Write},
+    path::Path,
+};
+
+use anyhow::{anyhow, Context, Result};
+use clap::ValueEnum;
+use serde::Serialize;
+use speedwayrs_types::scraper_types::GameInfo;
+
+/// Version of record layout, increased on every incompatible change of `GameInfo`.
+pub const SCHEMA_VERSION: u32 = 1;
+
+const NDJSON_FILE_NAME: &str = "scraping_result.jsonl";
+const ARRAY_FILE_NAME: &str = "scraping_result.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// One game per line (JSON Lines).
+    Ndjson,
+    /// Single JSON array with every game.
+    Array,
+    /// Both of the above.
+    Both,
+}
+
+#[derive(Serialize)]
+struct OutputRecord<'a> {
+    schema_version: u32,
+    #[serde(flatten)]
+    game: &'a GameInfo,
+}
+
+/// Writes scraped games to output files in selected format.
+/// Every record is flushed before `write` returns, so files stay valid if program is stopped.
+pub struct OutputWriter {
+    ndjson: Option<BufWriter<File>>,
+    array: Option<JsonArrayWriter>,
+}
+
+impl OutputWriter {
+    /// Opens output files in given folder. If `append` is false, previous content is discarded.
+    pub fn open(folder: &Path, format: OutputFormat, append: bool) -> Result<Self> {
+        let ndjson = match format {
+            OutputFormat::Ndjson | OutputFormat::Both => {
+                let path = folder.join(NDJSON_FILE_NAME);
+
+                let file = OpenOptions::new()
+                    .create(true)
+                    .write(true)
+                    .append(append)
+                    .truncate(!append)
+                    .open(&path)
+                    .with_context(|| format!("Unable to create file {}.", path.display()))?;
+
+                Some(BufWriter::new(file))
+            }
+            OutputFormat::Array => None,
+        };
+
+        let array = match format {
+            OutputFormat::Array | OutputFormat::Both => Some(JsonArrayWriter::open(
+                &folder.join(ARRAY_FILE_NAME),
+                append,
+            )?),
+            OutputFormat::Ndjson => None,
+        };
+
+        Ok(Self { ndjson, array })
+    }
+
+    pub fn write(&mut self, game: &GameInfo) -> Result<()> {
+        let record = OutputRecord {
+            schema_version: SCHEMA_VERSION,
+            game,
+        };
+
+        if let Some(ndjson) = self.ndjson.as_mut() {
+            serde_json::to_writer(&mut *ndjson, &record)?;
+            ndjson.write_all(b"\n")?;
+            ndjson.flush()?;
+        }
+
+        if let Some(array) = self.array.as_mut() {
+            array.write(&record)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// JSON array written incrementally. Closing bracket is written after every element
+/// and overwritten by the next one.
+struct JsonArrayWriter {
+    file: BufWriter<File>,
+    empty: bool,
+}
+
+impl JsonArrayWriter {
+    fn open(path: &Path, append: bool) -> Result<Self> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .read(true)
+            .write(true)
+            .truncate(false)
+            .open(path)
+            .with_context(|| format!("Unable to create file {}.", path.display()))?;
+
+        let mut content = String::new();
+        if append {
+            file.read_to_string(&mut content)
+                .with_context(|| format!("Unable to read file {}.", path.display()))?;
+        }
+
+        let (position, empty) = match array_end(&content) {
+            Some(end) => end,
+            None if content.trim().is_empty() => (0, true),
+            None => {
+                return Err(anyhow!(
+                    "File {} is not a JSON array and cannot be appended to.",
+                    path.display()
+                ))
+            }
+        };
+
+        file.set_len(position as u64)?;
+        file.seek(SeekFrom::Start(position as u64))?;
+
+        if position == 0 {
+            file.write_all(b"[")?;
+        }
+
+        let mut writer = Self {
+            file: BufWriter::new(file),
+            empty,
+        };
+        writer.close()?;
+
+        Ok(writer)
+    }
+
+    fn write<T: Serialize>(&mut self, value: &T) -> Result<()> {
+        if !self.empty {
+            self.file.write_all(b",")?;
+        }
+
+        self.file.write_all(b"\n")?;
+        serde_json::to_writer_pretty(&mut self.file, value)?;
+        self.empty = false;
+
+        self.close()
+    }
+
+    fn close(&mut self) -> Result<()> {
+        self.file.write_all(b"\n]")?;
+        self.file.flush()?;
+        self.file.seek(SeekFrom::Current(-2))?;
+
+        Ok(())
+    }
+}
+
+/// Returns position just after the last element of JSON array and whether the array is empty.
+fn array_end(content: &str) -> Option<(usize, bool)> {
+    let trimmed = content.trim();
+
+    if !trimmed.starts_with('[') || !trimmed.ends_with(']') {
+        return None;
+    }
+
+    let inner = content.trim_end().strip_suffix(']')?.trim_end();
+
+    Some((inner.len(), inner.trim_start() == "["))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::array_end;
+
+    #[test]
+    fn finding_array_end() {
+        assert_eq!(array_end("[\n]"), Some((1, true)));
+        assert_eq!(array_end("[\n{\"a\": 1}\n]\n"), Some((10, false)));
+        assert_eq!(array_end("{\"a\": 1}"), None);
+        assert_eq!(array_end(""), None);
+    }
+}
@@ -0,0 +1,222 @@
+use std::collections::HashSet;
+
+use speedwayrs_types::{
+    scraper_types::{Gap, Mismatch, Run, Team},
+    PlayerResult,
+};
+
+fn points(result: &PlayerResult) -> u8 {
+    match result {
+        PlayerResult::Score(points) | PlayerResult::ScoreWithStar(points) => *points,
+        _ => 0,
+    }
+}
+
+/// Returns true if lineup cell stands for a ride. Empty cells and `-` mark rides which
+/// were not taken.
+fn is_ride(result: &PlayerResult) -> bool {
+    !matches!(result, PlayerResult::None | PlayerResult::Reserve)
+}
+
+/// Compares rides of every rider in lineup with heats which rider is in.
+fn reconcile_rides(team: &Team, runs: &[&Run], mismatches: &mut Vec<Mismatch>) {
+    for player in team.players() {
+        let rider = player.full_name();
+
+        let lineup: Vec<_> = player.scores().iter().filter(|score| is_ride(score)).collect();
+        let heats: Vec<_> = runs
+            .iter()
+            .filter_map(|run| {
+                run.player_scores()
+                    .iter()
+                    .find(|score| score.name() == rider)
+                    .map(|score| (run.number(), score.score()))
+            })
+            .collect();
+
+        // Rides cannot be paired if any of them is missing.
+        if lineup.len() != heats.len() {
+            mismatches.push(Mismatch::RideCount {
+                rider,
+                lineup: lineup.len(),
+                heats: heats.len(),
+            });
+
+            continue;
+        }
+
+        for (lineup, (heat, heat_result)) in lineup.into_iter().zip(heats) {
+            if points(lineup) != points(heat_result) {
+                mismatches.push(Mismatch::Ride {
+                    rider: rider.clone(),
+                    heat,
+                    lineup: lineup.clone(),
+                    heat_result: heat_result.clone(),
+                });
+            }
+        }
+    }
+}
+
+/// Compares results of game listed in lineup tables with heat results and final score.
+/// Parts of game which could not be parsed are not compared, as their gaps are already
+/// recorded.
+pub fn reconcile(
+    team1: &Team,
+    team2: &Team,
+    runs: &[Run],
+    gaps: &[Gap],
+    final_score: Option<(u16, u16)>,
+) -> Vec<Mismatch> {
+    let mut mismatches = Vec::new();
+
+    let missing_lineup =
+        |team: u8| gaps.iter().any(|gap| matches!(gap, Gap::LineupRow { team: t, .. } if *t == team));
+    let missing_runs = gaps.iter().any(|gap| matches!(gap, Gap::Run { .. }));
+
+    let mut runs: Vec<_> = runs.iter().collect();
+    runs.sort_by_key(|run| run.number());
+
+    if !missing_runs {
+        reconcile_rides(team1, &runs, &mut mismatches);
+        reconcile_rides(team2, &runs, &mut mismatches);
+    }
+
+    if !missing_lineup(1) && !missing_lineup(2) {
+        let riders: HashSet<_> = team1
+            .players()
+            .iter()
+            .chain(team2.players())
+            .map(|player| player.full_name())
+            .collect();
+
+        for run in runs.iter() {
+            for score in run.player_scores() {
+                if !riders.contains(score.name()) {
+                    mismatches.push(Mismatch::UnknownRider {
+                        rider: score.name().into(),
+                        heat: run.number(),
+                    });
+                }
+            }
+        }
+    }
+
+    if let Some(final_score) = final_score {
+        for (number, team, score) in [(1, team1, final_score.0), (2, team2, final_score.1)] {
+            if !missing_lineup(number) && team.score() != score {
+                mismatches.push(Mismatch::TeamScore {
+                    team: number,
+                    lineup: team.score(),
+                    score,
+                });
+            }
+        }
+    }
+
+    mismatches
+}
+
+#[cfg(test)]
+mod tests {
+    use speedwayrs_types::{
+        scraper_types::{Gap, Mismatch, Player, PlayerRunScore, Run, Team},
+        PlayerResult,
+    };
+
+    use super::reconcile;
+
+    fn team(name: &str, riders: &[(&str, &[PlayerResult])]) -> Team {
+        let players: Vec<_> = riders
+            .iter()
+            .enumerate()
+            .map(|(number, (surname, scores))| {
+                Player::new("Jan".into(), surname.to_string(), number as u32, None, scores.to_vec())
+            })
+            .collect();
+        let points = players
+            .iter()
+            .flat_map(|player| player.scores())
+            .map(super::points)
+            .map(u16::from)
+            .sum();
+
+        Team::new(name.into(), points, players)
+    }
+
+    fn run(number: u8, results: &[(&str, PlayerResult)]) -> Run {
+        let scores = results
+            .iter()
+            .map(|(surname, score)| {
+                PlayerRunScore::new(format!("Jan {surname}"), score.clone(), None, None)
+            })
+            .collect();
+
+        Run::new(number, None, scores)
+    }
+
+    #[test]
+    fn matching_views() {
+        use PlayerResult::{None, Reserve, Score};
+
+        let team1 = team("A", &[("A", &[Score(3), None]), ("B", &[Reserve, Score(1)])]);
+        let team2 = team("B", &[("C", &[Score(2), Score(0)]), ("D", &[Score(0), Score(3)])]);
+        // Heats are paired with rides in order of their numbers, not as listed.
+        let runs = vec![
+            run(2, &[("B", Score(1)), ("C", Score(0)), ("D", Score(3))]),
+            run(1, &[("A", Score(3)), ("C", Score(2)), ("D", Score(0))]),
+        ];
+
+        assert!(reconcile(&team1, &team2, &runs, &[], Some((4, 5))).is_empty());
+    }
+
+    #[test]
+    fn finding_mismatches() {
+        use PlayerResult::Score;
+
+        let team1 = team("A", &[("A", &[Score(3), Score(2)]), ("B", &[Score(1)])]);
+        let team2 = team("B", &[("C", &[Score(2)])]);
+        let runs = vec![
+            run(1, &[("A", Score(3)), ("C", Score(2)), ("E", Score(1))]),
+            run(2, &[("A", Score(1)), ("C", Score(2))]),
+        ];
+
+        let mismatches = reconcile(&team1, &team2, &runs, &[], Some((6, 2)));
+
+        assert_eq!(
+            mismatches,
+            vec![
+                Mismatch::Ride {
+                    rider: "Jan A".into(),
+                    heat: 2,
+                    lineup: Score(2),
+                    heat_result: Score(1),
+                },
+                Mismatch::RideCount {
+                    rider: "Jan B".into(),
+                    lineup: 1,
+                    heats: 0,
+                },
+                Mismatch::RideCount {
+                    rider: "Jan C".into(),
+                    lineup: 1,
+                    heats: 2,
+                },
+                Mismatch::UnknownRider {
+                    rider: "Jan E".into(),
+                    heat: 1,
+                },
+            ]
+        );
+
+        // Riders cannot be compared with heats which are missing.
+        let gaps = vec![Gap::Run {
+            number: 3,
+            reason: "".into(),
+        }];
+        let mismatches = reconcile(&team1, &team2, &runs, &gaps, Some((6, 3)));
+
+        assert_eq!(mismatches.len(), 2);
+        assert!(matches!(mismatches[1], Mismatch::TeamScore { team: 2, lineup: 2, score: 3 }));
+    }
+}
@@ -0,0 +1,134 @@
+use std::{fmt::Display, fs::File, io::BufWriter, path::Path};
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use speedwayrs_types::scraper_types::Mismatch;
+
+const REPORT_FILE_NAME: &str = "scraping_errors.json";
+const MISMATCH_REPORT_FILE_NAME: &str = "scraping_mismatches.json";
+
+/// Step of game scraping in which an error occurred.
+/// Attached as context to errors, so it can be recovered while building the report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ScrapingStage {
+    Download,
+    Teams,
+    Runs,
+    Stadium,
+    Date,
+}
+
+impl Display for ScrapingStage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let description = match self {
+            Self::Download => "Problem with downloading game site.",
+            Self::Teams => "Problem with team parsing.",
+            Self::Runs => "Problem with runs parsing.",
+            Self::Stadium => "Problem with stadium parsing.",
+            Self::Date => "Problem with date parsing.",
+        };
+
+        write!(f, "{description}")
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ErrorEntry {
+    url: String,
+    stage: Option<ScrapingStage>,
+    // Error messages from the outermost context to the root cause.
+    chain: Vec<String>,
+}
+
+impl ErrorEntry {
+    pub fn new(url: &str, error: &anyhow::Error) -> Self {
+        Self {
+            url: url.into(),
+            stage: error.downcast_ref::<ScrapingStage>().copied(),
+            chain: error.chain().map(|cause| cause.to_string()).collect(),
+        }
+    }
+}
+
+/// Machine-readable list of games which could not be scraped.
+#[derive(Default)]
+pub struct ErrorReport {
+    entries: Vec<ErrorEntry>,
+    mismatches: Vec<MismatchEntry>,
+}
+
+/// Game which was saved, although its lineups disagree with heats or final score.
+#[derive(Debug, Serialize)]
+pub struct MismatchEntry {
+    url: String,
+    mismatches: Vec<Mismatch>,
+}
+
+impl MismatchEntry {
+    pub fn new(url: &str, mismatches: &[Mismatch]) -> Self {
+        Self {
+            url: url.into(),
+            mismatches: mismatches.to_vec(),
+        }
+    }
+}
+
+impl ErrorReport {
+    pub fn push(&mut self, entry: ErrorEntry) {
+        self.entries.push(entry);
+    }
+
+    pub fn push_mismatches(&mut self, entry: MismatchEntry) {
+        self.mismatches.push(entry);
+    }
+
+    /// Returns number of games saved with mismatches.
+    pub fn mismatched_games(&self) -> usize {
+        self.mismatches.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Saves errors and mismatches as JSON arrays in given folder, replacing reports
+    /// of previous run.
+    pub fn save(&self, folder: &Path) -> Result<()> {
+        Self::save_entries(&folder.join(REPORT_FILE_NAME), &self.entries)?;
+        Self::save_entries(&folder.join(MISMATCH_REPORT_FILE_NAME), &self.mismatches)
+    }
+
+    fn save_entries<T: Serialize>(path: &Path, entries: &[T]) -> Result<()> {
+        let file = File::create(path)
+            .with_context(|| format!("Unable to create report {}.", path.display()))?;
+
+        serde_json::to_writer_pretty(BufWriter::new(file), entries)
+            .with_context(|| format!("Unable to write report {}.", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::{anyhow, Context};
+
+    use super::{ErrorEntry, ScrapingStage};
+
+    #[test]
+    fn entry_from_error() {
+        let error = Err::<(), _>(anyhow!("Unable to find team 1 name."))
+            .context(ScrapingStage::Teams)
+            .unwrap_err();
+
+        let entry = ErrorEntry::new("https://a.pl/1", &error);
+
+        assert_eq!(entry.stage, Some(ScrapingStage::Teams));
+        assert_eq!(
+            entry.chain,
+            vec!["Problem with team parsing.", "Unable to find team 1 name."]
+        );
+    }
+}
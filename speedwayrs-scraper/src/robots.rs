@@ -0,0 +1,205 @@
+use std::time::Duration;
+
+use regex::Regex;
+
+struct Rule {
+    allow: bool,
+    // Length of the path pattern, longer patterns are more specific.
+    length: usize,
+    pattern: Regex,
+}
+
+/// Rules of robots.txt which apply to the scraper.
+/// Groups naming the scraper are used if there are any, otherwise the `*` group.
+/// The most specific matching rule decides, and `Allow` wins a tie.
+#[derive(Default)]
+pub struct Robots {
+    rules: Vec<Rule>,
+    crawl_delay: Option<Duration>,
+}
+
+#[derive(Default)]
+struct Group {
+    agents: Vec<String>,
+    rules: Vec<Rule>,
+    crawl_delay: Option<Duration>,
+}
+
+/// Translates path pattern with `*` wildcards and `$` end anchor into regex.
+fn compile_pattern(path: &str) -> Option<Regex> {
+    let (path, anchored) = match path.strip_suffix('$') {
+        Some(path) => (path, true),
+        None => (path, false),
+    };
+
+    let pattern = path
+        .split('*')
+        .map(regex::escape)
+        .collect::<Vec<_>>()
+        .join(".*");
+
+    Regex::new(&format!("^{pattern}{}", if anchored { "$" } else { "" })).ok()
+}
+
+impl Robots {
+    /// Parses robots.txt for user agent. Only its product token, e.g. `speedwayrs-scraper`
+    /// from `speedwayrs-scraper/0.1.0`, is compared with the groups.
+    pub fn parse(content: &str, user_agent: &str) -> Self {
+        let token = user_agent
+            .split('/')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_lowercase();
+
+        let mut groups: Vec<Group> = Vec::new();
+        // Consecutive `User-agent` lines start a single group.
+        let mut reading_agents = false;
+
+        for line in content.lines() {
+            let line = line.split('#').next().unwrap_or_default();
+
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let key = key.trim().to_lowercase();
+            let value = value.trim();
+
+            if key == "user-agent" {
+                if !reading_agents {
+                    groups.push(Group::default());
+                }
+
+                reading_agents = true;
+                groups
+                    .last_mut()
+                    .unwrap()
+                    .agents
+                    .push(value.to_lowercase());
+
+                continue;
+            }
+
+            reading_agents = false;
+
+            // Rules before the first group do not apply to anyone.
+            let Some(group) = groups.last_mut() else {
+                continue;
+            };
+
+            match key.as_str() {
+                "allow" | "disallow" if !value.is_empty() => {
+                    if let Some(pattern) = compile_pattern(value) {
+                        group.rules.push(Rule {
+                            allow: key == "allow",
+                            length: value.len(),
+                            pattern,
+                        });
+                    }
+                }
+                "crawl-delay" => {
+                    // Negative, infinite or NaN delays are ignored.
+                    group.crawl_delay = value
+                        .parse::<f64>()
+                        .ok()
+                        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok());
+                }
+                _ => {}
+            }
+        }
+
+        let named = |group: &Group| {
+            group
+                .agents
+                .iter()
+                .any(|agent| agent != "*" && token.starts_with(agent.as_str()))
+        };
+
+        let matching: Vec<Group> = if groups.iter().any(named) {
+            groups.into_iter().filter(named).collect()
+        } else {
+            groups
+                .into_iter()
+                .filter(|group| group.agents.iter().any(|agent| agent == "*"))
+                .collect()
+        };
+
+        let mut robots = Self::default();
+        for group in matching {
+            robots.crawl_delay = robots.crawl_delay.max(group.crawl_delay);
+            robots.rules.extend(group.rules);
+        }
+
+        robots
+    }
+
+    /// Returns true if site with given path (including query) may be downloaded.
+    pub fn allows(&self, path: &str) -> bool {
+        self.rules
+            .iter()
+            .filter(|rule| rule.pattern.is_match(path))
+            .max_by_key(|rule| (rule.length, rule.allow))
+            .is_none_or(|rule| rule.allow)
+    }
+
+    /// Returns minimal duration between requests requested by the site.
+    pub fn crawl_delay(&self) -> Option<Duration> {
+        self.crawl_delay
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::Robots;
+
+    const ROBOTS: &str = r#"
+        User-agent: *
+        Disallow: /zuzel/relacja/
+        Allow: /zuzel/relacja/*-live$
+        Crawl-delay: 2
+
+        User-agent: other-bot
+        User-agent: speedwayrs-scraper
+        Disallow: /private # comment
+        Crawl-delay: 0.5
+    "#;
+
+    #[test]
+    fn using_wildcard_group() {
+        let robots = Robots::parse(ROBOTS, "some-bot/1.0");
+
+        assert!(robots.allows("/zuzel/pge-ekstraliga/terminarz"));
+        assert!(!robots.allows("/zuzel/relacja/1001-unia-testowo-sparta-probna"));
+        assert!(robots.allows("/zuzel/relacja/1001-unia-testowo-sparta-probna-live"));
+        assert!(!robots.allows("/zuzel/relacja/1001-live?page=2"));
+        assert_eq!(robots.crawl_delay(), Some(Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn using_named_group() {
+        let robots = Robots::parse(ROBOTS, "speedwayrs-scraper/0.1.0");
+
+        assert!(robots.allows("/zuzel/relacja/1001-unia-testowo-sparta-probna"));
+        assert!(!robots.allows("/private/site"));
+        assert_eq!(robots.crawl_delay(), Some(Duration::from_millis(500)));
+    }
+
+    #[test]
+    fn allowing_everything_without_rules() {
+        let robots = Robots::parse("User-agent: *\nDisallow:\n", "speedwayrs-scraper/0.1.0");
+
+        assert!(robots.allows("/zuzel/relacja/1001"));
+        assert!(Robots::default().allows("/"));
+    }
+
+    #[test]
+    fn ignoring_invalid_crawl_delay() {
+        for delay in ["-1", "NaN", "inf", "soon"] {
+            let robots = Robots::parse(&format!("User-agent: *\nCrawl-delay: {delay}\n"), "some-bot/1.0");
+
+            assert_eq!(robots.crawl_delay(), None);
+        }
+    }
+}
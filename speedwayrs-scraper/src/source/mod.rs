@@ -0,0 +1,83 @@
+pub mod sportowefakty;
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use clap::ValueEnum;
+use speedwayrs_types::scraper_types::GameInfo;
+
+use crate::{game::GameSite, league::League, season::Season};
+
+/// Site with speedway results. Sources only parse sites, which are downloaded by the caller,
+/// so every source shares rate limiting, retries and cache of HTTP requests.
+pub trait Source: Send + Sync {
+    /// Returns url of league schedule for the current season.
+    fn schedule_url(&self, league: &League) -> String;
+
+    /// Returns every season of the league listed in its schedule.
+    fn parse_seasons(&self, body: &str, league: &League) -> Result<Vec<Season>>;
+
+    /// Returns games listed in schedule of a single season.
+    fn parse_schedule(&self, body: &str, league: &League) -> Result<Vec<GameSite>>;
+
+    /// Parses site of a single game.
+    fn parse_game(&self, body: &str, site: &GameSite) -> Result<GameInfo>;
+
+    /// Returns selectors used by the parsers, checked against downloaded sites.
+    fn selectors(&self) -> Vec<SelectorCheck>;
+}
+
+/// Kind of site downloaded from a source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageKind {
+    Schedule,
+    Game,
+}
+
+impl PageKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Schedule => "schedule",
+            Self::Game => "game",
+        }
+    }
+}
+
+/// Selector which should match at least `min_count` elements on every site of its kind.
+#[derive(Debug, Clone)]
+pub struct SelectorCheck {
+    pub name: &'static str,
+    pub page: PageKind,
+    pub selector: &'static str,
+    pub min_count: usize,
+}
+
+impl SelectorCheck {
+    pub fn new(
+        name: &'static str,
+        page: PageKind,
+        selector: &'static str,
+        min_count: usize,
+    ) -> Self {
+        Self {
+            name,
+            page,
+            selector,
+            min_count,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SourceKind {
+    /// sportowefakty.wp.pl
+    Sportowefakty,
+}
+
+impl SourceKind {
+    pub fn source(self) -> Arc<dyn Source> {
+        match self {
+            Self::Sportowefakty => Arc::new(sportowefakty::SportoweFakty),
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use once_cell::sync::OnceCell;
+use regex::Regex;
+use scraper::{Html, Selector};
+use speedwayrs_types::{MatchDetails, Stage};
+
+use crate::source::sportowefakty::selectors::DETAILS_ENTRY;
+
+/// Reads optional information listed next to game date and stadium.
+/// Entries missing on the site are left empty and do not make the game invalid.
+pub fn parse_details(parsed_body: &Html) -> MatchDetails {
+    static ENTRY_SELECTOR: OnceCell<Selector> = OnceCell::new();
+    static SPAN_SELECTOR: OnceCell<Selector> = OnceCell::new();
+
+    let entry_selector = ENTRY_SELECTOR.get_or_init(|| Selector::parse(DETAILS_ENTRY).unwrap());
+    let span_selector = SPAN_SELECTOR.get_or_init(|| Selector::parse("span").unwrap());
+
+    let mut round = None;
+    let mut stage = None;
+    let mut referee = None;
+    let mut track_commissioner = None;
+    let mut attendance = None;
+
+    for entry in parsed_body.select(entry_selector) {
+        let mut spans = entry.select(span_selector);
+
+        let (Some(label), Some(value)) = (spans.next(), spans.next()) else {
+            continue;
+        };
+
+        let label = label.text().collect::<String>().to_lowercase();
+        let label = label.trim().trim_end_matches(':');
+        let value = value.text().collect::<String>().trim().to_string();
+
+        if value.is_empty() {
+            continue;
+        }
+
+        match label {
+            "sędzia" => referee = Some(value),
+            "komisarz toru" => track_commissioner = Some(value),
+            "widzów" | "widzowie" | "publiczność" | "frekwencja" => {
+                attendance = parse_attendance(&value)
+            }
+            "runda" | "kolejka" | "faza" | "etap" | "rozgrywki" => {
+                let (parsed_stage, parsed_round) = parse_round(&value);
+
+                stage = parsed_stage.or(stage);
+                round = parsed_round.or(round);
+            }
+            _ => {}
+        }
+    }
+
+    MatchDetails::new(round, stage, referee, track_commissioner, attendance)
+}
+
+/// Reads number of spectators, e.g. `12 500` or `ok. 5000`.
+fn parse_attendance(text: &str) -> Option<u32> {
+    let digits: String = text.chars().filter(char::is_ascii_digit).collect();
+
+    digits.parse().ok()
+}
+
+/// Reads stage and, for regular season, round number from descriptions like
+/// `5. runda`, `kolejka 5`, `półfinał` or `finał, 2. mecz`.
+fn parse_round(text: &str) -> (Option<Stage>, Option<u8>) {
+    static ROUND_REGEX: OnceCell<Regex> = OnceCell::new();
+
+    let regex = ROUND_REGEX.get_or_init(|| {
+        Regex::new(
+            r"(?:(?P<BEFORE>\d+)\.?\s*(?:runda|kolejka))|(?:(?:runda|kolejka)\s*(?P<AFTER>\d+))",
+        )
+        .unwrap()
+    });
+
+    let text = text.to_lowercase();
+
+    if text.contains("półfinał")
+        || text.contains("ćwierćfinał")
+        || text.contains("play-off")
+        || text.contains("baraż")
+        || text.contains("o 3. miejsce")
+    {
+        return (Some(Stage::PlayOff), None);
+    }
+
+    if text.contains("finał") {
+        return (Some(Stage::Final), None);
+    }
+
+    match regex.captures(&text) {
+        Some(captures) => {
+            let round = captures
+                .name("BEFORE")
+                .or_else(|| captures.name("AFTER"))
+                .and_then(|number| number.as_str().parse().ok());
+
+            (Some(Stage::RegularSeason), round)
+        }
+        None => (None, None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use scraper::Html;
+
+    use speedwayrs_types::{MatchDetails, Stage};
+
+    use super::{parse_details, parse_round};
+
+    #[test]
+    fn parsing_round() {
+        assert_eq!(
+            parse_round("5. runda"),
+            (Some(Stage::RegularSeason), Some(5))
+        );
+        assert_eq!(
+            parse_round("Kolejka 12"),
+            (Some(Stage::RegularSeason), Some(12))
+        );
+        assert_eq!(
+            parse_round("2. Liga Żużlowa, 3. runda"),
+            (Some(Stage::RegularSeason), Some(3))
+        );
+        assert_eq!(
+            parse_round("Półfinał, 1. mecz"),
+            (Some(Stage::PlayOff), None)
+        );
+        assert_eq!(parse_round("Finał"), (Some(Stage::Final), None));
+        assert_eq!(parse_round("PGE Ekstraliga"), (None, None));
+    }
+
+    #[test]
+    fn parsing_match_details() {
+        let body = include_str!("../../../../fixtures/match_page.html");
+        let details = parse_details(&Html::parse_document(body));
+
+        assert_eq!(
+            details,
+            MatchDetails::new(
+                Some(5),
+                Some(Stage::RegularSeason),
+                Some("Artur Kuśmierz".into()),
+                Some("Jan Testowy".into()),
+                Some(12500),
+            )
+        );
+    }
+}
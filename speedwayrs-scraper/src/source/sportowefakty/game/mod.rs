@@ -0,0 +1,246 @@
+mod details;
+mod run;
+mod team;
+
+use anyhow::{anyhow, Context, Result};
+use once_cell::sync::OnceCell;
+use regex::Regex;
+use scraper::{Html, Selector};
+use speedwayrs_types::scraper_types::{Gap, GameInfo};
+use time::{macros::format_description, Month};
+
+use super::selectors::{DATE, FINAL_SCORE, STADIUM};
+use crate::{game::GameSite, reconcile::reconcile, report::ScrapingStage};
+
+fn parse_score(score_inner_html: &str) -> Result<(u16, u16)> {
+    static SCORE_REGEX: OnceCell<Regex> = OnceCell::new();
+
+    let regex =
+        SCORE_REGEX.get_or_init(|| Regex::new(r"(?P<SCORE1>\d+)(\D*)(?P<SCORE2>\d+)").unwrap());
+
+    let mut captures = regex.captures_iter(score_inner_html);
+    let matches = captures
+        .next()
+        .with_context(|| format!("Unable to find score in [{score_inner_html}]."))?;
+
+    let score_1 = &matches["SCORE1"];
+    let score_2 = &matches["SCORE2"];
+
+    Ok((
+        score_1
+            .parse()
+            .with_context(|| format!("Unable to parse score of first team [{score_1}]."))?,
+        score_2
+            .parse()
+            .with_context(|| format!("Unable to parse score of second team [{score_2}]."))?,
+    ))
+}
+
+/// Reads final score written next to team names, e.g. `52:38`.
+fn parse_final_score(parsed_body: &Html) -> Result<(u16, u16)> {
+    static SELECTOR: OnceCell<Selector> = OnceCell::new();
+
+    let selector = SELECTOR.get_or_init(|| Selector::parse(FINAL_SCORE).unwrap());
+
+    let score = parsed_body
+        .select(selector)
+        .next()
+        .context("Unable to select final score.")?
+        .inner_html();
+
+    parse_score(&score)
+}
+
+fn parse_stadium(parsed_body: &Html) -> Result<String> {
+    static SELECTOR: OnceCell<Selector> = OnceCell::new();
+
+    let selector = SELECTOR.get_or_init(|| Selector::parse(STADIUM).unwrap());
+
+    let stadium = parsed_body
+        .select(selector)
+        .next()
+        .context("Unable to select stadium")?;
+
+    Ok(stadium.inner_html())
+}
+
+fn parse_date(parsed_body: &Html) -> Result<time::PrimitiveDateTime> {
+    static SELECTOR: OnceCell<Selector> = OnceCell::new();
+
+    let selector = SELECTOR.get_or_init(|| Selector::parse(DATE).unwrap());
+
+    let date = parsed_body
+        .select(selector)
+        .next()
+        .context("Unable to select date.")?
+        .inner_html();
+
+    parse_date_text(&date)
+}
+
+/// Reads date written like `12 czerwca 2022, 19:15`.
+pub(super) fn parse_date_text(date: &str) -> Result<time::PrimitiveDateTime> {
+    let mut splitted_date = date.split_ascii_whitespace();
+
+    let day = splitted_date
+        .next()
+        .context("Unable to find day.")?
+        .parse()
+        .context("Unable to parse day.")?;
+    let month = splitted_date.next().context("Unable to find month.")?;
+    let year = splitted_date
+        .next()
+        .context("Unable to find year.")?
+        .trim_end_matches(',')
+        .parse()
+        .context("Unable to parse year.")?;
+    let time = splitted_date.next().context("Unable to find time.")?;
+
+    let month = {
+        match month.to_lowercase().as_str() {
+            "stycznia" => Month::January,
+            "lutego" => Month::February,
+            "marca" => Month::March,
+            "kwietnia" => Month::April,
+            "maja" => Month::May,
+            "czerwca" => Month::June,
+            "lipca" => Month::July,
+            "sierpnia" => Month::August,
+            "września" => Month::September,
+            "października" => Month::October,
+            "listopada" => Month::November,
+            "grudnia" => Month::December,
+            other => {
+                return Err(anyhow!("Month {other} is not parsable."));
+            }
+        }
+    };
+
+    let mut time_splitter = time.split_terminator(':');
+
+    let hour = time_splitter
+        .next()
+        .context("Unable to find hour.")?
+        .parse()
+        .context("Unable to parse hour.")?;
+    let minute = time_splitter
+        .next()
+        .context("Unable to find minutes.")?
+        .parse()
+        .context("Unable to parse minutes.")?;
+    let time = time::Time::from_hms(hour, minute, 0).context("Unable to create time.")?;
+
+    let date = time::Date::from_calendar_date(year, month, day)
+        .context("Unable to create date-time.")?;
+
+    Ok(time::PrimitiveDateTime::new(date, time))
+}
+
+/// Parses game site. Runs and lineup rows which cannot be parsed are recorded as gaps,
+/// while missing teams, stadium or date make the whole game invalid.
+/// Lineups which disagree with heats or final score are recorded as mismatches.
+pub fn parse_site(body: &str, site: &GameSite) -> Result<GameInfo> {
+    let parsed_body = Html::parse_document(body);
+
+    let (team1, team2, mut gaps) =
+        team::parse_teams(&parsed_body).context(ScrapingStage::Teams)?;
+
+    let mut runs = Vec::new();
+    for (number, run) in run::run_iterator(&parsed_body).context(ScrapingStage::Runs)? {
+        match run {
+            Ok(run) => runs.push(run),
+            Err(e) => gaps.push(Gap::Run {
+                number,
+                reason: format!("{e:#}"),
+            }),
+        }
+    }
+
+    let stadium = parse_stadium(&parsed_body).context(ScrapingStage::Stadium)?;
+    let date = parse_date(&parsed_body).context(ScrapingStage::Date)?;
+    let details = details::parse_details(&parsed_body);
+
+    // Games which have not started yet show no final score.
+    let final_score = parse_final_score(&parsed_body).ok();
+    let mismatches = reconcile(&team1, &team2, &runs, &gaps, final_score);
+
+    Ok(GameInfo::new(
+        site.league().slug().into(),
+        Some(site.url().into()),
+        team1,
+        team2,
+        stadium,
+        date,
+        site.status(),
+        details,
+        runs,
+        gaps,
+        mismatches,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use speedwayrs_types::{
+        scraper_types::{Gap, Mismatch},
+        MatchStatus,
+    };
+
+    use super::parse_site;
+    use crate::{game::GameSite, league::League};
+
+    const URL: &str = "https://sportowefakty.wp.pl/zuzel/relacja/1001-unia-testowo-sparta-probna";
+
+    fn match_site() -> GameSite {
+        GameSite::new(
+            URL.into(),
+            &League::new("pge-ekstraliga"),
+            MatchStatus::Finished,
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn parsing_match_site() {
+        let body = include_str!("../../../../fixtures/match_page.html");
+        let info = parse_site(body, &match_site()).unwrap();
+
+        assert_eq!(info.league(), "pge-ekstraliga");
+        assert_eq!(info.source_url(), Some(URL));
+        assert_eq!(info.status(), MatchStatus::Finished);
+        assert_eq!(info.place(), "Stadion Testowy, Testowo");
+        assert_eq!(info.date().to_string(), "2022-06-12 19:15:00.0");
+        assert_eq!(info.runs().len(), 15);
+        assert!(info.gaps().is_empty());
+        assert!(info.mismatches().is_empty());
+    }
+
+    #[test]
+    fn parsing_match_site_with_mismatches() {
+        let body = include_str!("../../../../fixtures/match_page.html")
+            .replacen("52:38", "53:38", 1);
+        let info = parse_site(&body, &match_site()).unwrap();
+
+        assert_eq!(
+            info.mismatches(),
+            [Mismatch::TeamScore {
+                team: 1,
+                lineup: 52,
+                score: 53
+            }]
+        );
+    }
+
+    #[test]
+    fn parsing_match_site_with_gaps() {
+        let body = include_str!("../../../../fixtures/match_page.html")
+            .replacen("<td>1</td>", "<td>?</td>", 1)
+            .replacen("<span class=\"competitor__score\">3</span>", "", 1);
+        let info = parse_site(&body, &match_site()).unwrap();
+
+        assert_eq!(info.runs().len(), 14);
+        assert!(matches!(info.gaps()[0], Gap::LineupRow { team: 1, row: 0, .. }));
+        assert!(matches!(info.gaps()[1], Gap::Run { number: 15, .. }));
+    }
+}
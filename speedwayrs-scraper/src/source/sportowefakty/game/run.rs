@@ -0,0 +1,340 @@
+use anyhow::{anyhow, Context, Result};
+use once_cell::sync::OnceCell;
+use regex::Regex;
+use scraper::{element_ref::Select, selector::Selector, ElementRef, Html};
+use speedwayrs_types::{
+    scraper_types::{PlayerRunScore, Run, Substitution},
+    Helmet, SubstitutionKind,
+};
+
+use super::team::parse_score;
+use crate::source::sportowefakty::selectors::{
+    COMPETITOR, COMPETITOR_NAME, COMPETITOR_SCORE, HELMET, RUN_LIST, RUN_TIME,
+};
+
+const RUN_COUNT: u8 = 15;
+
+fn parse_helmet(text: &str) -> Option<Helmet> {
+    if text.contains("red") {
+        Some(Helmet::Red)
+    } else if text.contains("blue") {
+        Some(Helmet::Blue)
+    } else if text.contains("yellow") {
+        Some(Helmet::Yellow)
+    } else if text.contains("white") {
+        Some(Helmet::White)
+    } else {
+        None
+    }
+}
+
+struct RunIterator<'a> {
+    remaining: u8,
+    players: Select<'a, 'static>,
+    time: Select<'a, 'static>,
+}
+
+impl<'a> RunIterator<'a> {
+    fn new(parsed_body: &'a Html) -> Result<Self> {
+        let parsed_body = parsed_body
+            .select(run_list_selector())
+            .next()
+            .context("Unable to find run list.")?;
+        let players = parsed_body.select(player_selector());
+        let time = parsed_body.select(time_selector());
+
+        Ok(Self {
+            remaining: RUN_COUNT,
+            players,
+            time,
+        })
+    }
+}
+
+fn parse_time(text: &str) -> Result<Option<(u16, u8)>> {
+    static TIME_REGEX: OnceCell<Regex> = OnceCell::new();
+
+    if text.len() == 0 {
+        return Ok(None);
+    }
+
+    let regex = TIME_REGEX
+        .get_or_init(|| Regex::new(r#"(?P<secs>\d+)(.(?P<ten_mills>\d+))? sek."#).unwrap());
+    let captures = regex
+        .captures(text)
+        .with_context(|| format!("Unable to parse {text}"))?;
+
+    let secs = &captures["secs"];
+    let ten_mills = captures
+        .name("ten_mills")
+        .map(|res| res.as_str())
+        .unwrap_or("0");
+
+    Ok(Some((
+        secs.parse()
+            .with_context(|| format!("Unable to parse seconds [{secs}]."))?,
+        ten_mills
+            .parse()
+            .with_context(|| format!("Unable to parse hundredths [{ten_mills}]."))?,
+    )))
+}
+
+/// Normalizes rider name. Everything after the first name is kept as surname,
+/// so compound surnames are not cut.
+fn parse_name(text: &str) -> Result<String> {
+    let parts: Vec<&str> = text.split_whitespace().collect();
+
+    if parts.len() < 2 {
+        return Err(anyhow!("Unable to parse names from {text}."));
+    }
+
+    Ok(parts.join(" "))
+}
+
+/// Reads substitution marker like `(zt za Jan Kowalski)` or `(j)` from competitor text.
+/// Jokers are recognized only by their marker, as points alone do not tell them apart.
+fn parse_substitution(text: &str) -> Option<Substitution> {
+    static SUBSTITUTION_REGEX: OnceCell<Regex> = OnceCell::new();
+
+    let regex = SUBSTITUTION_REGEX.get_or_init(|| {
+        Regex::new(r#"(?i)\((?P<kind>zt|zz|rt|j)\.?(\s+za\s+(?P<replaced>[^)]+?))?\s*\)"#).unwrap()
+    });
+
+    regex.captures(text).map(|captures| {
+        let kind = match captures["kind"].to_lowercase().as_str() {
+            "rt" => SubstitutionKind::TacticalReserve,
+            "zt" => SubstitutionKind::TacticalSubstitution,
+            "zz" => SubstitutionKind::RiderReplacement,
+            _ => SubstitutionKind::Joker,
+        };
+        let replaced = captures
+            .name("replaced")
+            .and_then(|replaced| parse_name(replaced.as_str()).ok());
+
+        Substitution::new(kind, replaced)
+    })
+}
+
+fn parse_competitor(element: &ElementRef) -> Result<PlayerRunScore> {
+    // Parsing name
+    let name = element
+        .select(player_name_selector())
+        .next()
+        .context("Unable to load player name.")?
+        .text()
+        .collect::<String>();
+
+    let name = parse_name(&name)?;
+
+    // Parsing score
+    let score_binding = element
+        .select(player_score_selector())
+        .next()
+        .context("Unable to load player's score.")?
+        .inner_html();
+
+    let score = parse_score(&score_binding)
+        .with_context(|| format!("Error parsing player score"))?;
+
+    // Parsing helmet icon
+    let helmet = parse_helmet(
+        &element
+            .select(player_helmet_selector())
+            .next()
+            .context("Unable to find helmet color.")?
+            .html(),
+    );
+
+    let substitution = parse_substitution(&element.text().collect::<String>());
+
+    Ok(PlayerRunScore::new(name, score, helmet, substitution))
+}
+
+impl<'a> RunIterator<'a> {
+    fn parse_run(&mut self, number: u8) -> Result<Run> {
+        let time = self.time.next();
+
+        // Competitors are taken before parsing, so a broken run does not shift the next ones.
+        let competitors: Vec<_> = self.players.by_ref().take(4).collect();
+
+        let time = time.with_context(|| format!("Unable to find time of run {number}."))?;
+        let time = parse_time(&time.inner_html())?;
+
+        if competitors.len() != 4 {
+            return Err(anyhow!(
+                "Found {} competitors of run {number}, expected 4.",
+                competitors.len()
+            ));
+        }
+
+        let mut players_score = Vec::new();
+        for competitor in competitors.iter() {
+            let parsed_competitor = parse_competitor(competitor)
+                .with_context(|| format!("Unable to parse competitor of run {number}."))?;
+
+            players_score.push(parsed_competitor);
+        }
+
+        Ok(Run::new(number, time, players_score))
+    }
+}
+
+impl<'a> Iterator for RunIterator<'a> {
+    /// Number of run along with its parsing result.
+    type Item = (u8, Result<Run>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let number = self.remaining;
+        self.remaining -= 1;
+
+        Some((number, self.parse_run(number)))
+    }
+}
+
+pub fn run_iterator(parsed_body: &Html) -> Result<impl Iterator<Item = (u8, Result<Run>)> + '_> {
+    RunIterator::new(parsed_body)
+}
+
+fn player_name_selector() -> &'static Selector {
+    static NAME_SELECTOR: OnceCell<Selector> = OnceCell::new();
+
+    NAME_SELECTOR.get_or_init(|| Selector::parse(COMPETITOR_NAME).unwrap())
+}
+
+fn player_score_selector() -> &'static Selector {
+    static SCORE_SELECTOR: OnceCell<Selector> = OnceCell::new();
+
+    SCORE_SELECTOR.get_or_init(|| Selector::parse(COMPETITOR_SCORE).unwrap())
+}
+
+fn player_helmet_selector() -> &'static Selector {
+    static HELMET_SELECTOR: OnceCell<Selector> = OnceCell::new();
+
+    HELMET_SELECTOR.get_or_init(|| Selector::parse(HELMET).unwrap())
+}
+
+fn player_selector() -> &'static Selector {
+    static PLAYER_SELECTOR: OnceCell<Selector> = OnceCell::new();
+
+    PLAYER_SELECTOR.get_or_init(|| Selector::parse(COMPETITOR).unwrap())
+}
+
+fn time_selector() -> &'static Selector {
+    static TIME_SELECTOR: OnceCell<Selector> = OnceCell::new();
+
+    TIME_SELECTOR.get_or_init(|| Selector::parse(RUN_TIME).unwrap())
+}
+
+fn run_list_selector() -> &'static Selector {
+    static RUN_LIST_SELECTOR: OnceCell<Selector> = OnceCell::new();
+
+    RUN_LIST_SELECTOR.get_or_init(|| Selector::parse(RUN_LIST).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use anyhow::Context;
+    use scraper::Html;
+
+    use speedwayrs_types::{scraper_types::Substitution, PlayerResult, SubstitutionKind};
+
+    use super::{parse_substitution, run_iterator};
+
+    #[test]
+    fn parsing_substitution() {
+        assert_eq!(
+            parse_substitution("Jan Kowalski (zt za Adam Nowak) 2"),
+            Some(Substitution::new(
+                SubstitutionKind::TacticalSubstitution,
+                Some("Adam Nowak".into()),
+            ))
+        );
+        assert_eq!(
+            parse_substitution("Jan Kowalski (J) 2").map(|substitution| substitution.kind()),
+            Some(SubstitutionKind::Joker)
+        );
+        // Points do not make a joker without the marker.
+        assert_eq!(parse_substitution("Jan Kowalski 4"), None);
+        assert_eq!(parse_substitution("Jan Kowalski 3"), None);
+    }
+
+    #[test]
+    fn substitution_in_match_site() {
+        let html = Html::parse_document(include_str!("../../../../fixtures/substitution_runs.html"));
+        let (number, run) = run_iterator(&html).unwrap().next().unwrap();
+        let run = run.unwrap();
+
+        assert_eq!(number, 15);
+        assert_eq!(run.player_scores()[0].name(), "Tomasz Wójcik");
+        assert_eq!(
+            run.player_scores()[0].substitution(),
+            Some(&Substitution::new(
+                SubstitutionKind::TacticalSubstitution,
+                Some("Marek Zieliński".into()),
+            ))
+        );
+        assert!(run.player_scores()[1].substitution().is_none());
+    }
+
+    #[test]
+    fn parsing_name() {
+        assert_eq!(super::parse_name("  Jan Kowalski ").unwrap(), "Jan Kowalski");
+        assert_eq!(super::parse_name("Jan  van der\nBerg").unwrap(), "Jan van der Berg");
+        assert!(super::parse_name("Kowalski").is_err());
+    }
+
+    #[test]
+    fn parsing_time() {
+        let time = "56.12 sek.";
+        let parsed_time = super::parse_time(time).unwrap();
+
+        assert!(parsed_time.unwrap().0 == 56);
+        assert!(parsed_time.unwrap().1 == 12);
+    }
+
+    #[test]
+    fn iterator_test() {
+        let site_fragment = r#"
+            <div class="coveragelist">
+                <span class="coventry__time">56.12 sek.</span>
+                <li class="competitor">
+                    <span class="competitor__name">John Test</span>
+                    <span class="competitor__score">5</span>
+                    <figure class="icon-helmet red"></figure>
+                </li>
+                <li class="competitor">
+                    <span class="competitor__name">Adam Test</span>
+                    <span class="competitor__score">6</span>
+                    <figure class="icon-helmet white"></figure>
+                </li>
+                <li class="competitor">
+                    <span class="competitor__name">Max Test</span>
+                    <span class="competitor__score">7</span>
+                    <figure class="icon-helmet blue"></figure>
+                </li>
+                <li class="competitor">
+                    <span class="competitor__name">Barb Test</span>
+                    <span class="competitor__score">8</span>
+                    <figure class="icon-helmet yellow"></figure>
+                </li>
+            </div>
+        "#;
+
+        let html = Html::parse_fragment(site_fragment);
+        let mut iter = run_iterator(&html).unwrap();
+
+        let (number, run) = iter.next().context("Run iterator does not work").unwrap();
+        let run = run.unwrap();
+
+        assert_eq!(number, 15);
+
+        assert_eq!(run.time().unwrap(), (56, 12));
+        assert_eq!(run.number(), 15);
+        assert_eq!(run.player_scores()[1].name(), "Adam Test");
+        assert_eq!(run.player_scores()[2].score(), &PlayerResult::Score(7));
+    }
+}
@@ -0,0 +1,107 @@
+mod player;
+
+use anyhow::Context;
+use anyhow::Result;
+use once_cell::sync::OnceCell;
+
+pub use player::parse_score;
+use scraper::ElementRef;
+use scraper::Html;
+use scraper::Selector;
+use speedwayrs_types::scraper_types::{Gap, Player, Team};
+
+use crate::source::sportowefakty::selectors::{TEAM_NAME, TEAM_ONE_PLAYERS, TEAM_TWO_PLAYERS};
+
+fn team_one_selector() -> &'static Selector {
+    static TEAM_ONE_SELECTOR: OnceCell<Selector> = OnceCell::new();
+
+    TEAM_ONE_SELECTOR.get_or_init(|| Selector::parse(TEAM_ONE_PLAYERS).unwrap())
+}
+
+fn team_two_selector() -> &'static Selector {
+    static TEAM_TWO_SELECTOR: OnceCell<Selector> = OnceCell::new();
+
+    TEAM_TWO_SELECTOR.get_or_init(|| Selector::parse(TEAM_TWO_PLAYERS).unwrap())
+}
+
+fn sum_score(players: &[Player]) -> u16 {
+    players.iter().map(player::sum_score).sum()
+}
+
+/// Parses lineup table. Rows which cannot be parsed are returned as gaps.
+fn parse_players(table: &ElementRef, team: u8) -> (Vec<Player>, Vec<Gap>) {
+    static TR_SELECTOR: OnceCell<Selector> = OnceCell::new();
+    let tr_selector = TR_SELECTOR.get_or_init(|| Selector::parse("tr").unwrap());
+
+    let mut result_players = Vec::new();
+    let mut gaps = Vec::new();
+    for (row, selected_player) in table.select(tr_selector).enumerate() {
+        match player::parse_player(selected_player) {
+            Ok(player) => result_players.push(player),
+            Err(e) => gaps.push(Gap::LineupRow {
+                team,
+                row,
+                reason: format!("{e:#}"),
+            }),
+        }
+    }
+
+    (result_players, gaps)
+}
+
+pub fn parse_teams(parsed_body: &Html) -> Result<(Team, Team, Vec<Gap>)> {
+    static TEAM_NAME_SELECTOR: OnceCell<Selector> = OnceCell::new();
+
+    let name_selector =
+        TEAM_NAME_SELECTOR.get_or_init(|| Selector::parse(TEAM_NAME).unwrap());
+
+    let mut team_names = parsed_body.select(&name_selector);
+    let team1_name = team_names
+        .next()
+        .context("Unable to find team 1 name.")?
+        .inner_html();
+    let team2_name = team_names
+        .next()
+        .context("Unable to find team 2 name.")?
+        .inner_html();
+
+    let team_1 = parsed_body
+        .select(team_one_selector())
+        .next()
+        .context("Unable to find team 1 players.")?;
+    let team_2 = parsed_body
+        .select(team_two_selector())
+        .next()
+        .context("Unable to find team 2 players.")?;
+
+    let (team_1, mut gaps) = parse_players(&team_1, 1);
+    let (team_2, team_2_gaps) = parse_players(&team_2, 2);
+    gaps.extend(team_2_gaps);
+
+    let team_one = Team::new(team1_name, sum_score(&team_1), team_1);
+    let team_two = Team::new(team2_name, sum_score(&team_2), team_2);
+
+    Ok((team_one, team_two, gaps))
+}
+
+#[cfg(test)]
+mod tests {
+    use scraper::Html;
+
+    use super::parse_teams;
+
+    #[test]
+    fn parsing_teams() {
+        let body = include_str!("../../../../../fixtures/match_page.html");
+        let parsed_body = Html::parse_document(body);
+
+        let (team_1, team_2, gaps) = parse_teams(&parsed_body).unwrap();
+
+        assert_eq!(team_1.name(), "Unia Testowo");
+        assert_eq!(team_1.score(), 52);
+        assert_eq!(team_1.players().len(), 5);
+        assert_eq!(team_2.name(), "Sparta Próbna");
+        assert_eq!(team_2.score(), 38);
+        assert!(gaps.is_empty());
+    }
+}
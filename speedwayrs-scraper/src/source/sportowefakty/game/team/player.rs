@@ -0,0 +1,203 @@
+use anyhow::{anyhow, Context, Result};
+use reqwest::Url;
+use scraper::{ElementRef, Selector};
+use speedwayrs_types::{scraper_types::Player, IncidentKind, PlayerResult};
+
+use crate::source::sportowefakty::BASE_SITE;
+
+/// Returns kind of incident marked with given symbol.
+fn incident_from_symbol(symbol: &str) -> Option<IncidentKind> {
+    Some(match symbol.to_lowercase().as_str() {
+        "u" | "u/-" | "u/" => IncidentKind::Fall,
+        "u/p" => IncidentKind::FallWhileLeading,
+        "u/w" => IncidentKind::FallExcluded,
+        "w/u" => IncidentKind::ExcludedForCausingFall,
+        "w" => IncidentKind::Exclusion,
+        "t" | "w/t" => IncidentKind::TapeTouch,
+        "w/2" | "w/2min" | "2min" | "2m" | "2'" => IncidentKind::TwoMinuteRule,
+        "d" => IncidentKind::Defect,
+        "ns" => IncidentKind::DidNotStart,
+        "nu" => IncidentKind::DidNotFinish,
+        _ => return None,
+    })
+}
+
+pub fn parse_score(text: &str) -> Result<PlayerResult> {
+    // Everything after the first tag (e.g. icons) is not a part of the score.
+    let text = text.split('<').next().unwrap_or_default().trim();
+
+    if let Some(kind) = incident_from_symbol(text) {
+        return Ok(PlayerResult::Incident {
+            kind,
+            symbol: text.into(),
+        });
+    }
+
+    Ok(match text {
+        "" => PlayerResult::None,
+        "-" => PlayerResult::Reserve,
+        num => {
+            if num.ends_with('*') {
+                let trimmed = num.trim_end_matches('*');
+                PlayerResult::ScoreWithStar(
+                    trimmed
+                        .parse()
+                        .map_err(|_| anyhow!("INVALID T {trimmed}"))?,
+                )
+            } else {
+                PlayerResult::Score(num.parse().map_err(|_| anyhow!("INVALID {num}"))?)
+            }
+        }
+    })
+}
+
+/// Sum of points scored by rider, without bonuses.
+pub fn sum_score(player: &Player) -> u16 {
+    player
+        .scores()
+        .iter()
+        .map(|score| match score {
+            PlayerResult::Score(score) | PlayerResult::ScoreWithStar(score) => *score as u16,
+            _ => 0,
+        })
+        .sum()
+}
+
+pub fn parse_player(element: ElementRef) -> Result<Player> {
+    let info_selector = Selector::parse("td").unwrap();
+
+    let mut selected = element.select(&info_selector);
+
+    let number: u32;
+    let name: String;
+    let surname: String;
+
+    // Parse number
+    let number_text = selected
+        .next()
+        .context("Unable to find player number.")?
+        .inner_html();
+    number = number_text
+        .trim()
+        .parse()
+        .with_context(|| format!("Unable to parse player number [{number_text}]."))?;
+
+    // Parse name
+    let link = selected
+        .next()
+        .context("Unable to find player credentials.")?
+        .select(&Selector::parse("a").unwrap())
+        .next()
+        .with_context(|| format!("Unable to find link to player {number}."))?
+        .value();
+    let credentials = link
+        .attr("title")
+        .with_context(|| format!("Unable to find title of player {number}."))?;
+    let source_url = link
+        .attr("href")
+        .map(|href| {
+            Url::parse(BASE_SITE)
+                .and_then(|base| base.join(href.trim()))
+                .map(String::from)
+                .with_context(|| format!("Invalid link [{href}] to player {number}."))
+        })
+        .transpose()?;
+
+    // Everything after the first name is a surname, so compound surnames are kept whole.
+    let (first_name, last_name) = credentials
+        .trim()
+        .split_once(char::is_whitespace)
+        .with_context(|| format!("Unable to find name and surname of player {number}."))?;
+
+    name = first_name.into();
+    surname = last_name.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    let mut scores = Vec::new();
+
+    for (index, match_score) in selected.enumerate() {
+        if index == 7 {
+            break;
+        }
+
+        scores.push(
+            parse_score(&match_score.inner_html())
+                .with_context(|| format!("PlayerScore parsing at run {number}."))?,
+        );
+    }
+
+    Ok(Player::new(name, surname, number, source_url, scores))
+}
+
+#[cfg(test)]
+mod tests {
+    use scraper::{Html, Selector};
+
+    use speedwayrs_types::{IncidentKind, PlayerResult};
+
+    use super::{parse_player, parse_score};
+
+    #[test]
+    fn parsing_player_row() {
+        let row = r#"
+            <table><tr>
+                <td>7</td>
+                <td><a href="/zuzel/zawodnik/77-jan-van-der-berg" title="Jan van der Berg">J. van der Berg</a></td>
+                <td>3</td>
+                <td>w/u</td>
+            </tr></table>
+        "#;
+        let html = Html::parse_fragment(row);
+        let element = html.select(&Selector::parse("tr").unwrap()).next().unwrap();
+        let player = parse_player(element).unwrap();
+
+        assert_eq!(player.name(), "Jan");
+        assert_eq!(player.surname(), "van der Berg");
+        assert_eq!(
+            player.source_url(),
+            Some("https://sportowefakty.wp.pl/zuzel/zawodnik/77-jan-van-der-berg")
+        );
+        assert_eq!(player.scores().len(), 2);
+    }
+
+    #[test]
+    fn parsing_absolute_player_link() {
+        let row = r#"
+            <table><tr>
+                <td>9</td>
+                <td><a href="https://sportowefakty.wp.pl/zuzel/zawodnik/12-jan-kowalski" title="Jan Kowalski">J. Kowalski</a></td>
+                <td>1</td>
+            </tr></table>
+        "#;
+        let html = Html::parse_fragment(row);
+        let element = html.select(&Selector::parse("tr").unwrap()).next().unwrap();
+        let player = parse_player(element).unwrap();
+
+        assert_eq!(
+            player.source_url(),
+            Some("https://sportowefakty.wp.pl/zuzel/zawodnik/12-jan-kowalski")
+        );
+    }
+
+    #[test]
+    fn parsing_player_score() {
+        assert_eq!(parse_score("2*").unwrap(), PlayerResult::ScoreWithStar(2));
+        assert_eq!(parse_score("3<i class=\"icon\"></i>").unwrap(), PlayerResult::Score(3));
+        assert_eq!(parse_score("<i></i>").unwrap(), PlayerResult::None);
+        assert_eq!(parse_score(" - ").unwrap(), PlayerResult::Reserve);
+        assert_eq!(
+            parse_score("W/U").unwrap(),
+            PlayerResult::Incident {
+                kind: IncidentKind::ExcludedForCausingFall,
+                symbol: "W/U".into(),
+            }
+        );
+        assert_eq!(
+            parse_score("w/2min").unwrap(),
+            PlayerResult::Incident {
+                kind: IncidentKind::TwoMinuteRule,
+                symbol: "w/2min".into(),
+            }
+        );
+        assert!(parse_score("x").is_err());
+    }
+}
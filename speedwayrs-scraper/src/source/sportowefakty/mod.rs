@@ -0,0 +1,60 @@
+mod game;
+mod schedule;
+mod season;
+mod selectors;
+
+use anyhow::Result;
+use scraper::Html;
+use speedwayrs_types::scraper_types::GameInfo;
+
+use super::{SelectorCheck, Source};
+use crate::{game::GameSite, league::League, season::Season};
+
+pub const BASE_SITE: &str = "https://sportowefakty.wp.pl";
+
+/// Returns path of league schedule relative to main site.
+fn relative_schedule(league: &League) -> String {
+    format!("/zuzel/{}/terminarz", league.slug())
+}
+
+/// Results published by sportowefakty.wp.pl.
+pub struct SportoweFakty;
+
+impl Source for SportoweFakty {
+    fn schedule_url(&self, league: &League) -> String {
+        format!("{BASE_SITE}{}", relative_schedule(league))
+    }
+
+    fn parse_seasons(&self, body: &str, league: &League) -> Result<Vec<Season>> {
+        season::parse_seasons(&Html::parse_document(body), league)
+    }
+
+    fn parse_schedule(&self, body: &str, league: &League) -> Result<Vec<GameSite>> {
+        schedule::parse_match_schedule(&Html::parse_document(body), league)
+    }
+
+    fn parse_game(&self, body: &str, site: &GameSite) -> Result<GameInfo> {
+        game::parse_site(body, site)
+    }
+
+    fn selectors(&self) -> Vec<SelectorCheck> {
+        selectors::checks()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{relative_schedule, SportoweFakty};
+    use crate::{league::League, source::Source};
+
+    #[test]
+    fn league_sites() {
+        let league = League::new("ewinner-1-liga");
+
+        assert_eq!(relative_schedule(&league), "/zuzel/ewinner-1-liga/terminarz");
+        assert_eq!(
+            SportoweFakty.schedule_url(&league),
+            "https://sportowefakty.wp.pl/zuzel/ewinner-1-liga/terminarz"
+        );
+    }
+}
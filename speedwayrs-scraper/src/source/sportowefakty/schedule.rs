@@ -0,0 +1,128 @@
+use anyhow::{Context, Result};
+use once_cell::sync::OnceCell;
+use scraper::{ElementRef, Html, Selector};
+use speedwayrs_types::MatchStatus;
+
+use super::{
+    game::parse_date_text,
+    selectors::{SCHEDULE_DATE, SCHEDULE_LINK, SCHEDULE_SCORE},
+    BASE_SITE,
+};
+use crate::{game::GameSite, league::League};
+
+/// Reads status from schedule entry. Games in progress, postponed and cancelled games
+/// are marked with a class of the entry, while played games show their score.
+fn parse_status(entry: &ElementRef) -> MatchStatus {
+    static SCORE_SELECTOR: OnceCell<Selector> = OnceCell::new();
+
+    let score_selector = SCORE_SELECTOR.get_or_init(|| Selector::parse(SCHEDULE_SCORE).unwrap());
+
+    for class in entry.value().classes() {
+        match class {
+            "cmatch--live" => return MatchStatus::Live,
+            "cmatch--postponed" => return MatchStatus::Postponed,
+            "cmatch--cancelled" => return MatchStatus::Cancelled,
+            _ => {}
+        }
+    }
+
+    let has_score = entry.select(score_selector).any(|score| {
+        score
+            .text()
+            .any(|text| text.chars().any(|c| c.is_ascii_digit()))
+    });
+
+    if has_score {
+        MatchStatus::Finished
+    } else {
+        MatchStatus::Scheduled
+    }
+}
+
+/// Reads planned date of game from schedule entry.
+fn parse_entry_date(entry: &ElementRef) -> Option<time::PrimitiveDateTime> {
+    static DATE_SELECTOR: OnceCell<Selector> = OnceCell::new();
+
+    let date_selector = DATE_SELECTOR.get_or_init(|| Selector::parse(SCHEDULE_DATE).unwrap());
+
+    let date = entry.select(date_selector).next()?.text().collect::<String>();
+
+    parse_date_text(&date).ok()
+}
+
+pub fn parse_match_schedule(parsed_body: &Html, league: &League) -> Result<Vec<GameSite>> {
+    let selector = Selector::parse(SCHEDULE_LINK).unwrap();
+
+    let mut result_vec = Vec::new();
+
+    for game_info in parsed_body.select(&selector) {
+        let relative_path = game_info
+            .value()
+            .attr("href")
+            .with_context(|| "Unable to find <href> attribute of game site.")?;
+
+        // Link text names both teams, e.g. `Unia Testowo - Sparta Próbna`.
+        let title = game_info.text().collect::<String>();
+        let teams = title
+            .split_once(" - ")
+            .map(|(team1, team2)| (team1.trim().to_string(), team2.trim().to_string()));
+
+        // Link is placed inside of schedule entry, which tells whether game was played.
+        let entry = game_info.parent().and_then(ElementRef::wrap);
+        let status = entry
+            .as_ref()
+            .map_or(MatchStatus::Scheduled, parse_status);
+        let date = entry.as_ref().and_then(parse_entry_date);
+
+        result_vec.push(GameSite::new(
+            format!("{BASE_SITE}{relative_path}"),
+            league,
+            status,
+            teams,
+            date,
+        ));
+    }
+
+    Ok(result_vec)
+}
+
+#[cfg(test)]
+mod tests {
+    use scraper::Html;
+    use speedwayrs_types::MatchStatus;
+
+    use super::parse_match_schedule;
+    use crate::league::League;
+
+    #[test]
+    fn parsing_schedule() {
+        let body = include_str!("../../../fixtures/schedule_page.html");
+        let league = League::new("pge-ekstraliga");
+        let games = parse_match_schedule(&Html::parse_document(body), &league).unwrap();
+
+        assert_eq!(games.len(), 4);
+        assert_eq!(games[1].league(), &league);
+        assert_eq!(games[0].status(), MatchStatus::Finished);
+        assert_eq!(games[1].status(), MatchStatus::Scheduled);
+        assert_eq!(games[2].status(), MatchStatus::Live);
+        assert_eq!(games[3].status(), MatchStatus::Postponed);
+        assert_eq!(
+            games[0].url(),
+            "https://sportowefakty.wp.pl/zuzel/relacja/1001-unia-testowo-sparta-probna"
+        );
+    }
+
+    #[test]
+    fn parsing_fixture() {
+        let body = include_str!("../../../fixtures/schedule_page.html");
+        let league = League::new("pge-ekstraliga");
+        let games = parse_match_schedule(&Html::parse_document(body), &league).unwrap();
+
+        let fixture = games[1].fixture().unwrap();
+
+        assert_eq!(fixture.team_one(), "Sparta Próbna");
+        assert_eq!(fixture.team_two(), "Unia Testowo");
+        assert_eq!(fixture.date().to_string(), "2022-06-19 17:00:00.0");
+        assert_eq!(fixture.status(), MatchStatus::Scheduled);
+    }
+}
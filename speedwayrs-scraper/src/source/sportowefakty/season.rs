@@ -0,0 +1,97 @@
+use anyhow::{Context, Result};
+use once_cell::sync::OnceCell;
+use regex::Regex;
+use scraper::{Html, Selector};
+
+use super::{
+    relative_schedule,
+    selectors::{CURRENT_SEASON, SEASON_MENU},
+    BASE_SITE,
+};
+use crate::{league::League, season::Season};
+
+fn parse_season(year_str: &str, relative_path: &str) -> Result<Season> {
+    static SEASON_REGEX: OnceCell<Regex> = OnceCell::new();
+
+    let regex = SEASON_REGEX.get_or_init(|| Regex::new(r"Sezon (\d+)").unwrap());
+
+    let season = &regex.captures_iter(year_str).next().with_context(|| {
+        format!(
+            "Season string is invalid. Expected 'SEZON {{}}'. GOT [{}].",
+            year_str
+        )
+    })?[1];
+
+    let year = season
+        .trim()
+        .parse()
+        .with_context(|| format!("Season number is invalid. Got [{}]", season))?;
+    let site = format!("{BASE_SITE}{relative_path}");
+
+    Ok(Season::new(year, site))
+}
+
+fn parse_current_season(parsed_body: &Html, league: &League) -> Result<Season> {
+    let selector = Selector::parse(CURRENT_SEASON).unwrap();
+
+    let current_season_info = parsed_body
+        .select(&selector)
+        .next()
+        .with_context(|| "Unable to find current season info.")?;
+    let season_info = current_season_info.inner_html();
+
+    parse_season(&season_info, &relative_schedule(league))
+}
+
+/// Returns every season listed in schedule of the league, starting with the current one.
+pub fn parse_seasons(parsed_body: &Html, league: &League) -> Result<Vec<Season>> {
+    let selector_dropdown_menu = Selector::parse(SEASON_MENU).unwrap();
+    let selector_li = Selector::parse("li").unwrap();
+    let selector_a = Selector::parse("a").unwrap();
+
+    let mut result_vec = Vec::new();
+    result_vec.push(parse_current_season(parsed_body, league)?);
+
+    let li_father = parsed_body
+        .select(&selector_dropdown_menu)
+        .next()
+        .with_context(|| "Unable to find dropdown menu with season dates.")?;
+    for li_element in li_father.select(&selector_li) {
+        let a_element = li_element
+            .select(&selector_a)
+            .next()
+            .with_context(|| "Unable to find <a> element.")?;
+
+        let season_number = a_element.inner_html();
+        let season_href = a_element
+            .value()
+            .attr("href")
+            .with_context(|| "Unable to find <href> element.")?;
+
+        result_vec.push(parse_season(&season_number, season_href)?);
+    }
+
+    Ok(result_vec)
+}
+
+#[cfg(test)]
+mod tests {
+    use scraper::Html;
+
+    use super::parse_seasons;
+    use crate::league::League;
+
+    #[test]
+    fn parsing_seasons() {
+        let body = include_str!("../../../fixtures/schedule_page.html");
+        let league = League::new("pge-ekstraliga");
+        let seasons = parse_seasons(&Html::parse_document(body), &league).unwrap();
+
+        assert_eq!(seasons.len(), 3);
+        assert_eq!(seasons[0].year(), 2022);
+        assert_eq!(
+            seasons[2].site(),
+            "https://sportowefakty.wp.pl/zuzel/pge-ekstraliga/terminarz/sezon-2020"
+        );
+    }
+}
@@ -0,0 +1,81 @@
+//! CSS selectors depending on markup of the site. When the site changes, they are
+//! reported by the `check` command.
+
+use crate::source::{PageKind, SelectorCheck};
+
+// Schedule of a league.
+pub(super) const CURRENT_SEASON: &str = "li.filtersitem:nth-child(1) > div:nth-child(1)";
+pub(super) const SEASON_MENU: &str =
+    "li.filtersitem:nth-child(1) > div:nth-child(2) > ul:nth-child(2)";
+pub(super) const SCHEDULE_LINK: &str = ".cmatch__link";
+pub(super) const SCHEDULE_DATE: &str = ".cmatch__date";
+pub(super) const SCHEDULE_SCORE: &str = ".cmatch__score";
+
+// Site of a game.
+pub(super) const TEAM_NAME: &str = ".mclabel__name > .name";
+pub(super) const FINAL_SCORE: &str = ".mclabel__score";
+pub(super) const TEAM_ONE_PLAYERS: &str = ".coveragetab__speedwaytables > div:nth-child(1) > table:nth-child(2) > tbody:nth-child(2)";
+pub(super) const TEAM_TWO_PLAYERS: &str = ".coveragetab__speedwaytables > div:nth-child(2) > table:nth-child(2) > tbody:nth-child(2)";
+pub(super) const DETAILS_ENTRY: &str = "ul.bdcd > li";
+pub(super) const DATE: &str = "ul.bdcd > li:nth-child(2) > span:nth-child(2)";
+pub(super) const STADIUM: &str = "ul.bdcd > li:nth-child(3) > span:nth-child(2) > a:nth-child(1)";
+pub(super) const RUN_LIST: &str = ".coveragelist";
+pub(super) const RUN_TIME: &str = ".coventry__time";
+pub(super) const COMPETITOR: &str = ".competitor";
+pub(super) const COMPETITOR_NAME: &str = ".competitor__name";
+pub(super) const COMPETITOR_SCORE: &str = ".competitor__score";
+pub(super) const HELMET: &str = ".icon-helmet";
+
+pub(super) fn checks() -> Vec<SelectorCheck> {
+    use PageKind::{Game, Schedule};
+
+    vec![
+        SelectorCheck::new("current season", Schedule, CURRENT_SEASON, 1),
+        SelectorCheck::new("season menu", Schedule, SEASON_MENU, 1),
+        SelectorCheck::new("game link", Schedule, SCHEDULE_LINK, 1),
+        SelectorCheck::new("game date", Schedule, SCHEDULE_DATE, 1),
+        // Schedule of a season which has just started has no scores.
+        SelectorCheck::new("game score", Schedule, SCHEDULE_SCORE, 0),
+        SelectorCheck::new("team names", Game, TEAM_NAME, 2),
+        SelectorCheck::new("final score", Game, FINAL_SCORE, 1),
+        SelectorCheck::new("team 1 lineup", Game, TEAM_ONE_PLAYERS, 1),
+        SelectorCheck::new("team 2 lineup", Game, TEAM_TWO_PLAYERS, 1),
+        SelectorCheck::new("details", Game, DETAILS_ENTRY, 3),
+        SelectorCheck::new("date", Game, DATE, 1),
+        SelectorCheck::new("stadium", Game, STADIUM, 1),
+        SelectorCheck::new("run list", Game, RUN_LIST, 1),
+        SelectorCheck::new("run time", Game, RUN_TIME, 1),
+        SelectorCheck::new("competitor", Game, COMPETITOR, 4),
+        SelectorCheck::new("competitor name", Game, COMPETITOR_NAME, 4),
+        SelectorCheck::new("competitor score", Game, COMPETITOR_SCORE, 4),
+        SelectorCheck::new("helmet", Game, HELMET, 4),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use scraper::{Html, Selector};
+
+    use super::checks;
+    use crate::source::PageKind;
+
+    #[test]
+    fn selectors_match_fixtures() {
+        let schedule = Html::parse_document(include_str!("../../../fixtures/schedule_page.html"));
+        let game = Html::parse_document(include_str!("../../../fixtures/match_page.html"));
+
+        for check in checks() {
+            let page = match check.page {
+                PageKind::Schedule => &schedule,
+                PageKind::Game => &game,
+            };
+            let selector = Selector::parse(check.selector).unwrap();
+
+            assert!(
+                page.select(&selector).count() >= check.min_count,
+                "Selector {} does not match fixture.",
+                check.name
+            );
+        }
+    }
+}
@@ -0,0 +1,190 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+    time::Duration,
+};
+
+use anyhow::{Context, Result};
+use speedwayrs_loader::pipeline::Pipeline;
+use speedwayrs_types::{scraper_types::GameInfo, MatchStatus};
+
+use crate::{
+    checkpoint::Checkpoint,
+    config::ProgramConfig,
+    game::GameSite,
+    http::HttpRequester,
+    league::League,
+    output::OutputWriter,
+    report::ScrapingStage,
+    source::Source,
+};
+
+/// Long-running mode which follows games of the current season.
+/// Games in progress are scraped on every poll, so their heats reach database as they are
+/// ridden. Finished games are scraped once more and recorded in the checkpoint once they
+/// are loaded into database. Other games are stored as fixtures.
+pub struct Watcher {
+    source: Arc<dyn Source>,
+    leagues: Vec<League>,
+    poll_interval: Duration,
+    pipeline: Pipeline,
+    output: Option<OutputWriter>,
+    checkpoint: Checkpoint,
+    statuses: HashMap<String, MatchStatus>,
+    /// Finished games saved to output, which are not confirmed to be loaded into database yet.
+    awaiting: HashSet<String>,
+    /// Number of games which failed to load, as of the previous poll.
+    failed: usize,
+}
+
+impl Watcher {
+    pub fn new(config: &ProgramConfig) -> Result<Self> {
+        let database_url = config
+            .database_url()
+            .context("Watch mode requires database url.")?;
+        let output_folder = config.output_folder();
+
+        // Every restart of the daemon continues previous output and checkpoint.
+        let output = config
+            .format()
+            .map(|format| OutputWriter::open(&output_folder, format, true))
+            .transpose()
+            .context("Unable to create file with parsing results.")?;
+        let checkpoint = Checkpoint::open(&output_folder, true)?;
+
+        let pipeline = Pipeline::connect(&database_url).context("Unable to connect to database.")?;
+
+        Ok(Self {
+            source: config.source(),
+            leagues: config.leagues(),
+            poll_interval: config.poll_interval(),
+            pipeline,
+            output,
+            checkpoint,
+            statuses: HashMap::new(),
+            awaiting: HashSet::new(),
+            failed: 0,
+        })
+    }
+
+    /// Polls schedules until the program is stopped.
+    pub fn run(mut self) -> Result<()> {
+        loop {
+            self.acknowledge();
+
+            for league in self.leagues.clone() {
+                if let Err(e) = self.poll(&league) {
+                    eprintln!(
+                        "ERROR: While polling schedule of league {} = [{:?}]",
+                        league.slug(),
+                        e
+                    );
+                }
+            }
+
+            std::thread::sleep(self.poll_interval);
+        }
+    }
+
+    /// Records finished games loaded since the previous poll and reports failed ones.
+    /// Finished games which failed to load are scraped again by the next poll.
+    fn acknowledge(&mut self) {
+        for url in self.pipeline.take_loaded() {
+            if self.awaiting.remove(&url) {
+                if let Err(e) = self.checkpoint.record(&url) {
+                    eprintln!("ERROR: While updating checkpoint = [{:?}]", e);
+                }
+            }
+        }
+
+        let failed = self.pipeline.summary().failed;
+
+        if failed > self.failed {
+            eprintln!(
+                "ERROR: Unable to load {} games into database since the previous poll.",
+                failed - self.failed
+            );
+        }
+        self.failed = failed;
+    }
+
+    fn poll(&mut self, league: &League) -> Result<()> {
+        let body = HttpRequester::make_request(&self.source.schedule_url(league))?;
+        let games = self.source.parse_schedule(&body, league)?;
+
+        for game in games {
+            let previous = self.statuses.insert(game.url().into(), game.status());
+
+            if let Some(previous) = previous.filter(|&previous| previous != game.status()) {
+                eprintln!(
+                    "Game {} changed status from {:?} to {:?}.",
+                    game.url(),
+                    previous,
+                    game.status()
+                );
+            }
+
+            let scrape = match game.status() {
+                MatchStatus::Live => true,
+                MatchStatus::Finished => !self.checkpoint.contains(game.url()),
+                // Fixture is stored when it is found and every time its status changes.
+                _ => {
+                    if previous != Some(game.status()) {
+                        if let Some(fixture) = game.fixture() {
+                            self.pipeline.send_fixture(fixture);
+                        }
+                    }
+
+                    false
+                }
+            };
+
+            if scrape {
+                if let Err(e) = self.scrape(&game) {
+                    eprintln!("ERROR: While scraping game {} = [{:?}]", game.url(), e);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn scrape(&mut self, game: &GameSite) -> Result<()> {
+        let body = HttpRequester::make_request(game.url()).context(ScrapingStage::Download)?;
+        let info = self.source.parse_game(&body, game)?;
+
+        if game.status() == MatchStatus::Finished && !info.mismatches().is_empty() {
+            eprintln!(
+                "WARNING: Lineups of game {} disagree with its heats or final score = [{:?}]",
+                game.url(),
+                info.mismatches()
+            );
+        }
+
+        // Games in progress are only loaded into database, output keeps final results.
+        let saved = if game.status() == MatchStatus::Finished {
+            self.save(game.url(), &info)
+        } else {
+            Ok(())
+        };
+
+        self.pipeline.send(info);
+
+        saved
+    }
+
+    /// Writes finished game to output once, even if loading it into database is retried.
+    fn save(&mut self, url: &str, info: &GameInfo) -> Result<()> {
+        if self.awaiting.contains(url) {
+            return Ok(());
+        }
+
+        if let Some(output) = self.output.as_mut() {
+            output.write(info)?;
+        }
+
+        self.awaiting.insert(url.into());
+
+        Ok(())
+    }
+}
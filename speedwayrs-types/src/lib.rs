@@ -1,14 +1,23 @@
+pub mod scraper_types;
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct MatchResult {
     team_1: String,
     team_2: String,
     score_1: u32,
     score_2: u32,
 
+    #[serde(with = "time::serde::rfc3339")]
     date: time::OffsetDateTime,
     place: String,
+    league: String,
+    #[serde(default)]
+    status: MatchStatus,
+    #[serde(default)]
+    details: MatchDetails,
 
     runs: Vec<RunInfo>,
     player_results: Vec<Player>,
@@ -22,6 +31,9 @@ impl MatchResult {
         score_2: u32,
         place: String,
         date: time::OffsetDateTime,
+        league: String,
+        status: MatchStatus,
+        details: MatchDetails,
         runs: Vec<RunInfo>,
         player_results: Vec<Player>,
     ) -> Self {
@@ -32,6 +44,9 @@ impl MatchResult {
             score_2,
             date,
             place,
+            league,
+            status,
+            details,
             runs,
             player_results,
         }
@@ -48,6 +63,18 @@ impl MatchResult {
         &self.runs
     }
 
+    pub fn league(&self) -> &str {
+        &self.league
+    }
+
+    pub fn status(&self) -> MatchStatus {
+        self.status
+    }
+
+    pub fn details(&self) -> &MatchDetails {
+        &self.details
+    }
+
     pub fn second_team_name(&self) -> &str {
         &self.team_2
     }
@@ -61,16 +88,336 @@ impl MatchResult {
     }
 }
 
+/// Part of the season in which game was played.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum Stage {
+    RegularSeason,
+    PlayOff,
+    Final,
+}
+
+impl Stage {
+    /// Name used in the database.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Stage::RegularSeason => "regular_season",
+            Stage::PlayOff => "play_off",
+            Stage::Final => "final",
+        }
+    }
+
+    pub fn to_pretty(&self) -> &'static str {
+        match self {
+            Stage::RegularSeason => "Runda zasadnicza",
+            Stage::PlayOff => "Play-off",
+            Stage::Final => "Finał",
+        }
+    }
+}
+
+impl std::str::FromStr for Stage {
+    type Err = String;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        match source {
+            "regular_season" => Ok(Stage::RegularSeason),
+            "play_off" => Ok(Stage::PlayOff),
+            "final" => Ok(Stage::Final),
+            other => Err(format!("Unknown stage [{other}].")),
+        }
+    }
+}
+
+/// State of game in league schedule. Only finished games have score and runs.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum MatchStatus {
+    Scheduled,
+    Live,
+    Postponed,
+    Cancelled,
+    #[default]
+    Finished,
+}
+
+impl MatchStatus {
+    /// Name used in the database.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MatchStatus::Scheduled => "scheduled",
+            MatchStatus::Live => "live",
+            MatchStatus::Postponed => "postponed",
+            MatchStatus::Cancelled => "cancelled",
+            MatchStatus::Finished => "finished",
+        }
+    }
+
+    pub fn to_pretty(&self) -> &'static str {
+        match self {
+            MatchStatus::Scheduled => "Zaplanowany",
+            MatchStatus::Live => "Na żywo",
+            MatchStatus::Postponed => "Przełożony",
+            MatchStatus::Cancelled => "Odwołany",
+            MatchStatus::Finished => "Zakończony",
+        }
+    }
+
+    /// Returns true if game was played, at least partially.
+    pub fn is_played(&self) -> bool {
+        matches!(self, MatchStatus::Live | MatchStatus::Finished)
+    }
+}
+
+impl std::str::FromStr for MatchStatus {
+    type Err = String;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        match source {
+            "scheduled" => Ok(MatchStatus::Scheduled),
+            "live" => Ok(MatchStatus::Live),
+            "postponed" => Ok(MatchStatus::Postponed),
+            "cancelled" => Ok(MatchStatus::Cancelled),
+            "finished" => Ok(MatchStatus::Finished),
+            other => Err(format!("Unknown match status [{other}].")),
+        }
+    }
+}
+
+/// Optional information about game, which is not shown on every game site.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct MatchDetails {
+    round: Option<u8>,
+    stage: Option<Stage>,
+    referee: Option<String>,
+    track_commissioner: Option<String>,
+    attendance: Option<u32>,
+}
+
+impl MatchDetails {
+    pub fn new(
+        round: Option<u8>,
+        stage: Option<Stage>,
+        referee: Option<String>,
+        track_commissioner: Option<String>,
+        attendance: Option<u32>,
+    ) -> Self {
+        Self {
+            round,
+            stage,
+            referee,
+            track_commissioner,
+            attendance,
+        }
+    }
+
+    pub fn round(&self) -> Option<u8> {
+        self.round
+    }
+
+    pub fn stage(&self) -> Option<Stage> {
+        self.stage
+    }
+
+    pub fn referee(&self) -> Option<&str> {
+        self.referee.as_deref()
+    }
+
+    pub fn track_commissioner(&self) -> Option<&str> {
+        self.track_commissioner.as_deref()
+    }
+
+    pub fn attendance(&self) -> Option<u32> {
+        self.attendance
+    }
+}
+
+/// Helmet colour, which tells from which gate rider started.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum Helmet {
+    Red,
+    Yellow,
+    Blue,
+    White,
+}
+
+impl Helmet {
+    /// Starting gate, counted from the inside of the track.
+    pub fn gate(&self) -> u8 {
+        match self {
+            Helmet::Red => 1,
+            Helmet::Blue => 2,
+            Helmet::White => 3,
+            Helmet::Yellow => 4,
+        }
+    }
+
+    /// Name used in the database.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Helmet::Red => "Red",
+            Helmet::Yellow => "Yellow",
+            Helmet::Blue => "Blue",
+            Helmet::White => "White",
+        }
+    }
+
+    pub fn to_pretty(&self) -> &'static str {
+        match self {
+            Helmet::Red => "Czerwony",
+            Helmet::Yellow => "Żółty",
+            Helmet::Blue => "Niebieski",
+            Helmet::White => "Biały",
+        }
+    }
+}
+
+impl std::str::FromStr for Helmet {
+    type Err = String;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        match source {
+            "Red" => Ok(Helmet::Red),
+            "Yellow" => Ok(Helmet::Yellow),
+            "Blue" => Ok(Helmet::Blue),
+            "White" => Ok(Helmet::White),
+            other => Err(format!("Unknown helmet colour [{other}].")),
+        }
+    }
+}
+
+/// Reason why rider took a ride which was not scheduled for him.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum SubstitutionKind {
+    TacticalReserve,
+    TacticalSubstitution,
+    RiderReplacement,
+    Joker,
+}
+
+impl SubstitutionKind {
+    /// Name used in the database.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SubstitutionKind::TacticalReserve => "tactical_reserve",
+            SubstitutionKind::TacticalSubstitution => "tactical_substitution",
+            SubstitutionKind::RiderReplacement => "rider_replacement",
+            SubstitutionKind::Joker => "joker",
+        }
+    }
+
+    pub fn to_pretty(&self) -> &'static str {
+        match self {
+            SubstitutionKind::TacticalReserve => "Rezerwa taktyczna",
+            SubstitutionKind::TacticalSubstitution => "Zmiana taktyczna",
+            SubstitutionKind::RiderReplacement => "Zastępstwo zawodnika",
+            SubstitutionKind::Joker => "Joker",
+        }
+    }
+}
+
+impl std::str::FromStr for SubstitutionKind {
+    type Err = String;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        match source {
+            "tactical_reserve" => Ok(SubstitutionKind::TacticalReserve),
+            "tactical_substitution" => Ok(SubstitutionKind::TacticalSubstitution),
+            "rider_replacement" => Ok(SubstitutionKind::RiderReplacement),
+            "joker" => Ok(SubstitutionKind::Joker),
+            other => Err(format!("Unknown substitution kind [{other}].")),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Substitution {
+    kind: SubstitutionKind,
+    // Identifier and name of the rider who was scheduled for the ride, if known.
+    replaced_player: Option<(i32, String)>,
+}
+
+impl Substitution {
+    pub fn new(kind: SubstitutionKind, replaced_player: Option<(i32, String)>) -> Self {
+        Self {
+            kind,
+            replaced_player,
+        }
+    }
+
+    pub fn kind(&self) -> SubstitutionKind {
+        self.kind
+    }
+
+    pub fn replaced_player(&self) -> Option<&(i32, String)> {
+        self.replaced_player.as_ref()
+    }
+}
+
+/// Result of single rider in a run.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct RunPlayerScore {
+    player_id: i32,
+    name: String,
+    // Serialized `PlayerResult`.
+    score: String,
+    helmet: Option<Helmet>,
+    substitution: Option<Substitution>,
+}
+
+impl RunPlayerScore {
+    pub fn new(
+        player_id: i32,
+        name: String,
+        score: String,
+        helmet: Option<Helmet>,
+        substitution: Option<Substitution>,
+    ) -> Self {
+        Self {
+            player_id,
+            name,
+            score,
+            helmet,
+            substitution,
+        }
+    }
+
+    pub fn player_id(&self) -> i32 {
+        self.player_id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn score(&self) -> &str {
+        &self.score
+    }
+
+    pub fn helmet(&self) -> Option<Helmet> {
+        self.helmet
+    }
+
+    pub fn substitution(&self) -> Option<&Substitution> {
+        self.substitution.as_ref()
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct RunInfo {
     number: u8,
     time: Option<(u32, u16)>,
-    // First field represents player name and second field represents player's score.
-    player_scores: Vec<(i32, String, String)>,
+    player_scores: Vec<RunPlayerScore>,
 }
 
 impl RunInfo {
-    pub fn scores(&self) -> &[(i32, String, String)] {
+    pub fn scores(&self) -> &[RunPlayerScore] {
         &self.player_scores
     }
 
@@ -91,15 +438,52 @@ impl RunInfo {
     }
 }
 
-#[derive(Serialize, Deserialize)]
+/// Reason why rider did not score in a ride.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum IncidentKind {
+    Fall,
+    FallWhileLeading,
+    FallExcluded,
+    ExcludedForCausingFall,
+    Exclusion,
+    TapeTouch,
+    TwoMinuteRule,
+    Defect,
+    DidNotStart,
+    DidNotFinish,
+}
+
+impl IncidentKind {
+    pub fn to_pretty(&self) -> &'static str {
+        match self {
+            IncidentKind::Fall => "Upadek",
+            IncidentKind::FallWhileLeading => "Upadek na prowadzeniu",
+            IncidentKind::FallExcluded => "Upadek i wykluczenie",
+            IncidentKind::ExcludedForCausingFall => "Wykluczenie za spowodowanie upadku",
+            IncidentKind::Exclusion => "Wykluczenie",
+            IncidentKind::TapeTouch => "Taśma",
+            IncidentKind::TwoMinuteRule => "Przekroczenie 2 minut",
+            IncidentKind::Defect => "Defekt",
+            IncidentKind::DidNotStart => "Nie startował",
+            IncidentKind::DidNotFinish => "Nie ukończono",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub enum PlayerResult {
     Score(u8),
     ScoreWithStar(u8),
+    // `Fall`, `Defect`, `Tape` and `NotFinished` are written only by older loader versions.
     Fall,
     Reserve,
     Defect,
     Tape,
     NotFinished,
+    /// Ride without points, along with symbol used on the scraped site.
+    Incident { kind: IncidentKind, symbol: String },
     None,
 }
 
@@ -112,6 +496,18 @@ impl PlayerResult {
         serde_json::to_string(self).unwrap()
     }
 
+    /// Returns reason of ride without points, if there was any.
+    pub fn incident(&self) -> Option<IncidentKind> {
+        match self {
+            PlayerResult::Fall => Some(IncidentKind::Fall),
+            PlayerResult::Defect => Some(IncidentKind::Defect),
+            PlayerResult::Tape => Some(IncidentKind::TapeTouch),
+            PlayerResult::NotFinished => Some(IncidentKind::DidNotFinish),
+            PlayerResult::Incident { kind, .. } => Some(*kind),
+            _ => None,
+        }
+    }
+
     pub fn to_pretty(&self) -> String {
         match self {
             PlayerResult::Score(score) => format!("{}", score),
@@ -121,12 +517,14 @@ impl PlayerResult {
             PlayerResult::Defect => "Defekt".into(),
             PlayerResult::Tape => "Taśma".into(),
             PlayerResult::NotFinished => "Nie ukończono".into(),
+            PlayerResult::Incident { kind, symbol } => format!("{} ({symbol})", kind.to_pretty()),
             PlayerResult::None => "Brak wyniku?".into()
         }
     }
 }
 
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Player {
     name: String,
     surname: String,
@@ -147,7 +545,10 @@ impl Player {
 }
 
 impl RunInfo {
-    pub fn new(number: u8, time: Option<(u32, u16)>, player_scores: Vec<(i32, String, String)>) -> Self {
+    /// Creates run with riders ordered by their starting gate.
+    pub fn new(number: u8, time: Option<(u32, u16)>, mut player_scores: Vec<RunPlayerScore>) -> Self {
+        player_scores.sort_by_key(|score| score.helmet.map_or(u8::MAX, |helmet| helmet.gate()));
+
         Self {
             number,
             time,
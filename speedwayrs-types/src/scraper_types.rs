@@ -1,49 +1,371 @@
-use time::PrimitiveDateTime;
-use serde::{Serialize, Deserialize};
+//! Games as written by the scraper and read by the loader.
 
-#[derive(Debug, Serialize, Deserialize)]
-pub enum Helmet {
-    Red,
-    Yellow,
-    Blue,
-    White,
-}
+use serde::{Deserialize, Serialize};
+
+use crate::{Helmet, MatchDetails, MatchStatus, PlayerResult, SubstitutionKind};
 
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Player {
     name: String,
     surname: String,
     number: u32,
+    // Profile site of the rider, which identifies him better than his name.
+    // Missing in files scraped before riders were identified by their profile site.
+    #[serde(default)]
+    source_url: Option<String>,
+
+    scores: Vec<PlayerResult>,
+}
+
+impl Player {
+    pub fn new(
+        name: String,
+        surname: String,
+        number: u32,
+        source_url: Option<String>,
+        scores: Vec<PlayerResult>,
+    ) -> Self {
+        Self {
+            name,
+            surname,
+            number,
+            source_url,
+            scores,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn surname(&self) -> &str {
+        &self.surname
+    }
 
-    scores: Vec<super::PlayerResult>,
+    /// Name in the form used in run results.
+    pub fn full_name(&self) -> String {
+        format!("{} {}", self.name, self.surname)
+    }
+
+    pub fn number(&self) -> u32 {
+        self.number
+    }
+
+    pub fn source_url(&self) -> Option<&str> {
+        self.source_url.as_deref()
+    }
+
+    pub fn scores(&self) -> &[PlayerResult] {
+        self.scores.as_slice()
+    }
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Team {
     name: String,
     points: u16,
-    players: Vec<Player>
+    players: Vec<Player>,
+}
+
+impl Team {
+    pub fn new(name: String, points: u16, players: Vec<Player>) -> Self {
+        Self {
+            name,
+            points,
+            players,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn score(&self) -> u16 {
+        self.points
+    }
+
+    pub fn players(&self) -> &[Player] {
+        self.players.as_slice()
+    }
 }
 
-#[derive(Serialize, Deserialize)]
+/// Part of game site which could not be parsed. Game is saved without it.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum Gap {
+    Run { number: u8, reason: String },
+    LineupRow { team: u8, row: usize, reason: String },
+}
+
+/// Results which differ between parts of game site describing the same rides.
+/// Game is saved with them, so they can be corrected by hand.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Mismatch {
+    /// Ride of rider has different points in lineup row and in heat results.
+    Ride {
+        rider: String,
+        heat: u8,
+        lineup: PlayerResult,
+        heat_result: PlayerResult,
+    },
+    /// Lineup row lists different number of rides than heats which rider is in.
+    RideCount {
+        rider: String,
+        lineup: usize,
+        heats: usize,
+    },
+    /// Rider of heat is not in any lineup.
+    UnknownRider { rider: String, heat: u8 },
+    /// Points summed from lineup differ from final score of the game.
+    TeamScore { team: u8, lineup: u16, score: u16 },
+}
+
+fn default_league() -> String {
+    "pge-ekstraliga".into()
+}
+
+#[derive(Debug, Serialize, Deserialize)]
 pub struct GameInfo {
+    // Files scraped before multiple leagues were supported contain only PGE Ekstraliga games.
+    #[serde(default = "default_league")]
+    league: String,
+    // Missing in files written before scraper stored site of every game.
+    #[serde(default)]
+    source_url: Option<String>,
     team1: Team,
     team2: Team,
     stadium: String,
     date: time::PrimitiveDateTime,
-    runs: Vec<Run>
+    // Files written before fixtures were stored contain only finished games.
+    #[serde(default)]
+    status: MatchStatus,
+    #[serde(default)]
+    details: MatchDetails,
+    runs: Vec<Run>,
+    #[serde(default)]
+    gaps: Vec<Gap>,
+    #[serde(default)]
+    mismatches: Vec<Mismatch>,
+}
+
+impl GameInfo {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        league: String,
+        source_url: Option<String>,
+        team1: Team,
+        team2: Team,
+        stadium: String,
+        date: time::PrimitiveDateTime,
+        status: MatchStatus,
+        details: MatchDetails,
+        runs: Vec<Run>,
+        gaps: Vec<Gap>,
+        mismatches: Vec<Mismatch>,
+    ) -> Self {
+        Self {
+            league,
+            source_url,
+            team1,
+            team2,
+            stadium,
+            date,
+            status,
+            details,
+            runs,
+            gaps,
+            mismatches,
+        }
+    }
+
+    pub fn league(&self) -> &str {
+        &self.league
+    }
+
+    pub fn source_url(&self) -> Option<&str> {
+        self.source_url.as_deref()
+    }
+
+    pub fn team_one(&self) -> &Team {
+        &self.team1
+    }
+
+    pub fn team_two(&self) -> &Team {
+        &self.team2
+    }
+
+    pub fn place(&self) -> &str {
+        &self.stadium
+    }
+
+    pub fn date(&self) -> &time::PrimitiveDateTime {
+        &self.date
+    }
+
+    pub fn status(&self) -> MatchStatus {
+        self.status
+    }
+
+    pub fn details(&self) -> &MatchDetails {
+        &self.details
+    }
+
+    pub fn runs(&self) -> &[Run] {
+        self.runs.as_slice()
+    }
+
+    pub fn gaps(&self) -> &[Gap] {
+        self.gaps.as_slice()
+    }
+
+    pub fn mismatches(&self) -> &[Mismatch] {
+        self.mismatches.as_slice()
+    }
+}
+
+/// Game listed in league schedule which was not played yet.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Fixture {
+    league: String,
+    source_url: String,
+    team1: String,
+    team2: String,
+    date: time::PrimitiveDateTime,
+    status: MatchStatus,
+}
+
+impl Fixture {
+    pub fn new(
+        league: String,
+        source_url: String,
+        team1: String,
+        team2: String,
+        date: time::PrimitiveDateTime,
+        status: MatchStatus,
+    ) -> Self {
+        Self {
+            league,
+            source_url,
+            team1,
+            team2,
+            date,
+            status,
+        }
+    }
+
+    pub fn league(&self) -> &str {
+        &self.league
+    }
+
+    pub fn source_url(&self) -> &str {
+        &self.source_url
+    }
+
+    pub fn team_one(&self) -> &str {
+        &self.team1
+    }
+
+    pub fn team_two(&self) -> &str {
+        &self.team2
+    }
+
+    pub fn date(&self) -> &time::PrimitiveDateTime {
+        &self.date
+    }
+
+    pub fn status(&self) -> MatchStatus {
+        self.status
+    }
+}
+
+/// Marks a ride which was not taken according to the heat schedule.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Substitution {
+    kind: SubstitutionKind,
+    // Rider who was scheduled for this ride, if site names him.
+    replaced: Option<String>,
 }
 
-#[derive(Serialize, Deserialize)]
+impl Substitution {
+    pub fn new(kind: SubstitutionKind, replaced: Option<String>) -> Self {
+        Self { kind, replaced }
+    }
+
+    pub fn kind(&self) -> SubstitutionKind {
+        self.kind
+    }
+
+    pub fn replaced(&self) -> Option<&str> {
+        self.replaced.as_deref().map(str::trim)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
 pub struct PlayerRunScore {
     name: String,
-    score: super::PlayerResult,
-    helmet: Option<Helmet>
+    score: PlayerResult,
+    helmet: Option<Helmet>,
+    #[serde(default)]
+    substitution: Option<Substitution>,
 }
 
-#[derive(Serialize, Deserialize)]
+impl PlayerRunScore {
+    pub fn new(
+        name: String,
+        score: PlayerResult,
+        helmet: Option<Helmet>,
+        substitution: Option<Substitution>,
+    ) -> Self {
+        Self {
+            name,
+            score,
+            helmet,
+            substitution,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.trim()
+    }
+
+    pub fn score(&self) -> &PlayerResult {
+        &self.score
+    }
+
+    pub fn helmet(&self) -> Option<Helmet> {
+        self.helmet
+    }
+
+    pub fn substitution(&self) -> Option<&Substitution> {
+        self.substitution.as_ref()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Run {
     number: u8,
+    // Seconds and hundredths.
     time: Option<(u16, u8)>,
-    player_score: Vec<PlayerRunScore>
+    player_score: Vec<PlayerRunScore>,
+}
+
+impl Run {
+    pub fn new(number: u8, time: Option<(u16, u8)>, player_score: Vec<PlayerRunScore>) -> Self {
+        Self {
+            number,
+            time,
+            player_score,
+        }
+    }
+
+    pub fn number(&self) -> u8 {
+        self.number
+    }
+
+    pub fn time(&self) -> Option<(u16, u8)> {
+        self.time
+    }
+
+    pub fn player_scores(&self) -> &[PlayerRunScore] {
+        self.player_score.as_slice()
+    }
 }